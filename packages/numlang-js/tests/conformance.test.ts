@@ -0,0 +1,99 @@
+// Runs the shared fixture corpus in `conformance/` at the repository root.
+// Cases marked `skip.js` are known divergences from the Rust package.
+import { readdirSync, readFileSync } from "fs";
+import { join } from "path";
+import { describe, it, expect } from "vitest";
+import numlang from "../src";
+import { NumberToWordsOptions } from "../src/options";
+
+const FIXTURES = join(__dirname, "../../../conformance");
+
+interface Case {
+  input: number | string;
+  options?: Record<string, unknown>;
+  expected?: unknown;
+  error?: boolean;
+  skip?: { rust?: string; js?: string };
+}
+
+interface Fixture {
+  function: string;
+  cases: Case[];
+}
+
+// The fixture `case` option maps onto the JS `uppercase` and `capitalize`
+// flags. Words are generated in lowercase, so "lower" and "preserve" are the
+// defaults; there is no title case.
+function options(fixture: Record<string, unknown> = {}): NumberToWordsOptions {
+  const opts: NumberToWordsOptions = {
+    useCommas: fixture.use_commas === true,
+    useAnd: fixture.use_and === true,
+    appendOnly: fixture.append_only === true,
+  };
+  switch (fixture.case) {
+    case undefined:
+    case "lower":
+    case "preserve":
+      break;
+    case "upper":
+      opts.uppercase = true;
+      break;
+    case "sentence":
+      opts.capitalize = true;
+      break;
+    default:
+      throw new Error(`unsupported case '${fixture.case}'`);
+  }
+  return opts;
+}
+
+// Converts a UTF-16 index into `input` to a UTF-8 byte offset, as used by
+// the fixtures.
+function byteOffset(input: string, index: number): number {
+  return new TextEncoder().encode(input.slice(0, index)).length;
+}
+
+const functions: Record<string, (input: any, opts: NumberToWordsOptions) => unknown> = {
+  to_words: (input, opts) => numlang.toWords(input, opts),
+  to_ordinal: (input) => numlang.toOrdinal(input),
+  to_words_ordinal: (input, opts) => numlang.toWordsOrdinal(input, opts),
+  from_words: (input) => numlang.fromWords(input),
+  tokenise: (input: string) =>
+    numlang.tokenise(input).map((span) => ({
+      type: span.token.type,
+      value: span.token.value,
+      start: byteOffset(input, span.start),
+      end: byteOffset(input, span.end),
+    })),
+  expand_unit: (input) => numlang.expandUnit(input) ?? null,
+  abbreviate_unit: (input) => numlang.abbreviateUnit(input) ?? null,
+  to_plural: (input) => numlang.toPlural(input),
+  to_singular: (input) => numlang.toSingular(input),
+};
+
+for (const file of readdirSync(FIXTURES).filter((f) => f.endsWith(".json"))) {
+  const fixture: Fixture = JSON.parse(readFileSync(join(FIXTURES, file), "utf8"));
+  const run = functions[fixture.function];
+
+  describe(`conformance: ${fixture.function}`, () => {
+    it("is a shared function", () => {
+      expect(run).toBeDefined();
+    });
+
+    fixture.cases.forEach((c, i) => {
+      const name = `case ${i}: ${JSON.stringify(c.input)}`;
+      if (c.skip?.js) {
+        it.skip(`${name} (${c.skip.js})`, () => {});
+        return;
+      }
+      it(name, () => {
+        const call = () => run(c.input, options(c.options));
+        if (c.error) {
+          expect(call).toThrow();
+        } else {
+          expect(call()).toEqual(c.expected);
+        }
+      });
+    });
+  });
+}
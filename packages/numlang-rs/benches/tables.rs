@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use numlang::{abbreviate_unit, expand_unit, to_words, tokenise, unit_type, write_words, Options};
+use std::hint::black_box;
+
+fn bench_expand_unit(c: &mut Criterion) {
+    c.bench_function("expand_unit", |b| b.iter(|| expand_unit(black_box("mg"))));
+}
+
+fn bench_unit_type(c: &mut Criterion) {
+    c.bench_function("unit_type (abbreviation)", |b| {
+        b.iter(|| unit_type(black_box("ml")))
+    });
+    c.bench_function("unit_type (expanded plural)", |b| {
+        b.iter(|| unit_type(black_box("milliliters")))
+    });
+}
+
+fn bench_abbreviate_unit(c: &mut Criterion) {
+    c.bench_function("abbreviate_unit", |b| {
+        b.iter(|| abbreviate_unit(black_box("milligrams")))
+    });
+}
+
+fn bench_tokenise(c: &mut Criterion) {
+    c.bench_function("tokenise", |b| {
+        b.iter(|| {
+            tokenise(black_box(
+                "Give 2 tablets (20mg/kg) twice daily, then 1 blue scoop.",
+            ))
+        })
+    });
+}
+
+fn bench_words(c: &mut Criterion) {
+    let opts = Options::new().use_and(true);
+    c.bench_function("to_words", |b| {
+        b.iter(|| to_words(black_box(1_234_567.89), &opts))
+    });
+    let mut out = String::with_capacity(128);
+    c.bench_function("write_words (reused buffer)", |b| {
+        b.iter(|| {
+            out.clear();
+            write_words(&mut out, black_box(1_234_567.89), &opts)
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_expand_unit,
+    bench_unit_type,
+    bench_abbreviate_unit,
+    bench_tokenise,
+    bench_words
+);
+criterion_main!(benches);
@@ -0,0 +1,98 @@
+/*
+ * C interface to numlang. Build the crate with the `ffi` feature as a
+ * static or dynamic library (for example
+ * `cargo rustc --release --features ffi --crate-type staticlib`).
+ *
+ * Strings and token lists returned by numlang belong to the caller and must
+ * be released with numlang_string_free and numlang_tokens_free.
+ */
+#ifndef NUMLANG_H
+#define NUMLANG_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* One status per kind of parse error. */
+typedef enum numlang_status {
+    NUMLANG_OK = 0,
+    NUMLANG_ERR_NULL_ARGUMENT = 1,
+    NUMLANG_ERR_INVALID_UTF8 = 2,
+    /* A word that isn't part of a number. */
+    NUMLANG_ERR_UNKNOWN_WORD = 3,
+    /* A non-digit after "point". */
+    NUMLANG_ERR_INVALID_DECIMAL = 4,
+    NUMLANG_ERR_OVERFLOW = 5,
+    /* Strict mode: number words in an order that isn't a number. */
+    NUMLANG_ERR_MALFORMED = 6
+} numlang_status;
+
+/* Values for numlang_options.letter_case. */
+#define NUMLANG_CASE_PRESERVE 0
+#define NUMLANG_CASE_LOWER 1
+#define NUMLANG_CASE_UPPER 2
+#define NUMLANG_CASE_SENTENCE 3
+#define NUMLANG_CASE_TITLE 4
+
+/* Formatting options. A zeroed struct gives the defaults; any nonzero flag
+ * is true. */
+typedef struct numlang_options {
+    uint8_t use_commas;
+    uint8_t use_and;
+    uint8_t append_only;
+    int32_t letter_case;
+} numlang_options;
+
+typedef enum numlang_token_kind {
+    NUMLANG_TOKEN_NUMBER_WORD = 0,
+    NUMLANG_TOKEN_NUMBER_STRING = 1,
+    NUMLANG_TOKEN_UNIT = 2,
+    NUMLANG_TOKEN_UNKNOWN = 3
+} numlang_token_kind;
+
+/* A token with its byte positions in the input. */
+typedef struct numlang_token_span {
+    numlang_token_kind kind;
+    char *text;
+    size_t start;
+    size_t end;
+} numlang_token_span;
+
+typedef struct numlang_tokens {
+    numlang_token_span *spans;
+    size_t len;
+} numlang_tokens;
+
+/* Number in words. options may be NULL for the defaults. Returns NULL if
+ * letter_case is out of range. NaN and infinities are written as "not a
+ * number" and "infinity". */
+char *numlang_to_words(double number, const numlang_options *options);
+
+/* Ordinal in words ("twenty-first"). */
+char *numlang_to_words_ordinal(int64_t number, const numlang_options *options);
+
+/* Ordinal in digits ("21st"). */
+char *numlang_to_ordinal(int64_t number);
+
+/* Parses number words or digits into *value. On failure, if error is not
+ * NULL, *error receives the message. */
+numlang_status numlang_from_words(const char *text, bool strict, double *value, char **error);
+
+/* Splits text into tokens. Release *tokens with numlang_tokens_free. */
+numlang_status numlang_tokenise(const char *text, numlang_tokens *tokens);
+
+/* Releases a string returned by numlang. NULL is ignored. */
+void numlang_string_free(char *s);
+
+/* Releases the spans in *tokens and empties it. */
+void numlang_tokens_free(numlang_tokens *tokens);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* NUMLANG_H */
@@ -0,0 +1,94 @@
+use crate::cardinal::to_words;
+use crate::unit::expand_unit;
+use crate::Options;
+
+/// Letters whose names start with a vowel sound ("an F", "an ml").
+const VOWEL_SOUND_LETTERS: [char; 12] =
+    ['a', 'e', 'f', 'h', 'i', 'l', 'm', 'n', 'o', 'r', 's', 'x'];
+
+/// Word beginnings spelled with a vowel but spoken with a consonant
+/// ("a unit", "a one-off", "a ewe"). "uni-" is listed stem by stem, since
+/// "un-" followed by "i" is spoken with a vowel ("an unidentified").
+const CONSONANT_SOUND_PREFIXES: [&str; 19] = [
+    "one", "once", "eu", "ewe", "unic", "unif", "unil", "unio", "uniq", "unis", "unit", "univ",
+    "use", "usu", "ure", "uri", "ute", "uti", "uv",
+];
+
+/// Word beginnings with a silent "h" ("an hour").
+const SILENT_H_PREFIXES: [&str; 4] = ["hour", "honest", "hono", "heir"];
+
+/// Returns "a" or "an" for the phrase, going by how its first word is
+/// spoken rather than spelled.
+///
+/// Numbers are read as words ("an 8 mg tablet", "an 11-year-old",
+/// "a 1 ml dose"). Unit abbreviations without vowels are read letter by
+/// letter ("an ml", "a kg"); others are read as the unit name ("an oz",
+/// "a tab"). Other short capitalised words are treated as initialisms
+/// ("an NSAID").
+pub fn indefinite_article(phrase: &str) -> &'static str {
+    let phrase = phrase.trim_start();
+    let word: String = phrase
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || matches!(c, '.' | ','))
+        .collect();
+    let word = word.trim_end_matches(['.', ',']);
+    if word.starts_with(|c: char| c.is_ascii_digit()) {
+        return number_article(word);
+    }
+    if let Some(name) = expand_unit(word) {
+        if !name.eq_ignore_ascii_case(word) {
+            return if is_initialism(word) {
+                letter_article(word)
+            } else {
+                word_article(&name)
+            };
+        }
+    }
+    if word.chars().count() == 1 || (is_initialism(word) && word.len() <= 5) {
+        return letter_article(word);
+    }
+    word_article(word)
+}
+
+/// Reads the leading number of a word such as "8", "1,000", "11th" or "8.5".
+fn number_article(word: &str) -> &'static str {
+    let digits: String = word
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',')
+        .filter(|c| *c != ',')
+        .collect();
+    match digits.parse::<f64>() {
+        Ok(value) => word_article(&to_words(value, &Options::default())),
+        Err(_) => "a",
+    }
+}
+
+/// True for words read letter by letter: all capitals ("IU", "NSAID"),
+/// mixed case ("mEq") or no vowels ("ml", "tbsp").
+fn is_initialism(word: &str) -> bool {
+    let has_lower = word.chars().any(|c| c.is_lowercase());
+    let has_vowel = word.chars().any(|c| "aeiouAEIOU".contains(c));
+    let upper_after_first = word.chars().skip(1).any(|c| c.is_uppercase());
+    !has_lower || upper_after_first || !has_vowel
+}
+
+fn letter_article(word: &str) -> &'static str {
+    match word.chars().next() {
+        Some(c) if VOWEL_SOUND_LETTERS.contains(&c.to_ascii_lowercase()) => "an",
+        _ => "a",
+    }
+}
+
+fn word_article(word: &str) -> &'static str {
+    let lc = word.to_lowercase();
+    if SILENT_H_PREFIXES.iter().any(|p| lc.starts_with(p)) {
+        return "an";
+    }
+    if CONSONANT_SOUND_PREFIXES.iter().any(|p| lc.starts_with(p)) {
+        return "a";
+    }
+    match lc.chars().next() {
+        Some('a' | 'e' | 'i' | 'o' | 'u') => "an",
+        _ => "a",
+    }
+}
@@ -0,0 +1,274 @@
+//! `numlang` command-line tool. Build with `--features cli`.
+
+use numlang::tokenise::Token;
+use numlang::{
+    from_words_with, parse::parse_exact_with, resolve_unit, to_ordinal, to_plural, to_words,
+    to_words_ordinal, tokenise, Case, Options, ParseMode, UnitCase,
+};
+use serde_json::{json, Value};
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: numlang <COMMAND> [FLAGS] [INPUT...]
+
+Converts each INPUT, or each line of standard input if none are given.
+
+Commands:
+  words      Write numbers in words (\"42\" -> \"forty-two\")
+  ordinal    Write integers as ordinal words (\"3\" -> \"third\")
+  parse      Read number words (\"two and a half\" -> 2.5)
+  tokenise   Split text into number, unit and other tokens with byte positions
+  units      Look up unit abbreviations and names
+
+Flags:
+  --json            Print one JSON object per input
+  --commas          words, ordinal: separate scale groups with commas
+  --and             words, ordinal: put \"and\" after hundreds
+  --only            words, ordinal: end with \"only\"
+  --case <CASE>     words, ordinal: lower, upper, sentence, title or preserve
+  --digits          ordinal: write \"3rd\" rather than \"third\"
+  --strict          parse: reject phrases that aren't well-formed numbers
+  --exact           parse: print exact values (\"2/3\", \"12.50\")
+  -h, --help        Print this help
+";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Command {
+    Words,
+    Ordinal,
+    Parse,
+    Tokenise,
+    Units,
+}
+
+struct Args {
+    command: Command,
+    json: bool,
+    options: Options,
+    digits: bool,
+    mode: ParseMode,
+    exact: bool,
+    inputs: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("numlang: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("numlang: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Returns None if help was asked for.
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut args = args.peekable();
+    let command = match args.next().as_deref() {
+        None | Some("-h" | "--help" | "help") => return Ok(None),
+        Some("words") => Command::Words,
+        Some("ordinal") => Command::Ordinal,
+        Some("parse") => Command::Parse,
+        Some("tokenise" | "tokenize") => Command::Tokenise,
+        Some("units") => Command::Units,
+        Some(other) => return Err(format!("unknown command '{}'", other)),
+    };
+    let mut parsed = Args {
+        command,
+        json: false,
+        options: Options::new(),
+        digits: false,
+        mode: ParseMode::Lenient,
+        exact: false,
+        inputs: Vec::new(),
+    };
+    let mut flags_done = false;
+    while let Some(arg) = args.next() {
+        // Anything not starting "--" is input, so "-5" is a number.
+        if flags_done || !arg.starts_with("--") && arg != "-h" {
+            parsed.inputs.push(arg);
+            continue;
+        }
+        let takes_number_options = matches!(command, Command::Words | Command::Ordinal);
+        match arg.as_str() {
+            "--" => flags_done = true,
+            "-h" | "--help" => return Ok(None),
+            "--json" => parsed.json = true,
+            "--commas" if takes_number_options => parsed.options = parsed.options.use_commas(true),
+            "--and" if takes_number_options => parsed.options = parsed.options.use_and(true),
+            "--only" if takes_number_options => parsed.options = parsed.options.append_only(true),
+            "--case" if takes_number_options => {
+                let case = match args.next().as_deref() {
+                    Some("lower") => Case::Lower,
+                    Some("upper") => Case::Upper,
+                    Some("sentence") => Case::SentenceCase,
+                    Some("title") => Case::TitleCase,
+                    Some("preserve") => Case::Preserve,
+                    Some(other) => return Err(format!("unknown case '{}'", other)),
+                    None => return Err("--case needs a value".to_string()),
+                };
+                parsed.options = parsed.options.case(case);
+            }
+            "--digits" if command == Command::Ordinal => parsed.digits = true,
+            "--strict" if command == Command::Parse => parsed.mode = ParseMode::Strict,
+            "--exact" if command == Command::Parse => parsed.exact = true,
+            _ => return Err(format!("unknown flag '{}' for this command", arg)),
+        }
+    }
+    Ok(Some(parsed))
+}
+
+/// Converts every input, returning false if any couldn't be converted.
+fn run(args: &Args) -> io::Result<bool> {
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let mut all_ok = true;
+    let mut emit = |input: &str, out: &mut BufWriter<_>| -> io::Result<()> {
+        match convert(args, input) {
+            Ok(Output { text, json }) => {
+                if args.json {
+                    writeln!(out, "{}", json)
+                } else {
+                    writeln!(out, "{}", text)
+                }
+            }
+            Err(message) => {
+                all_ok = false;
+                if args.json {
+                    writeln!(out, "{}", json!({ "input": input, "error": message }))
+                } else {
+                    eprintln!("numlang: {}: {}", input, message);
+                    Ok(())
+                }
+            }
+        }
+    };
+    if args.inputs.is_empty() {
+        for line in io::stdin().lock().lines() {
+            emit(&line?, &mut out)?;
+        }
+    } else {
+        for input in &args.inputs {
+            emit(input, &mut out)?;
+        }
+    }
+    out.flush()?;
+    Ok(all_ok)
+}
+
+struct Output {
+    text: String,
+    json: Value,
+}
+
+fn convert(args: &Args, input: &str) -> Result<Output, String> {
+    let trimmed = input.trim();
+    let output = |text: String, json: Value| Output { text, json };
+    match args.command {
+        Command::Words => {
+            let number: f64 = trimmed.parse().map_err(|_| "not a number".to_string())?;
+            if !number.is_finite() {
+                return Err("not a finite number".to_string());
+            }
+            let words = to_words(number, &args.options);
+            Ok(output(
+                words.clone(),
+                json!({ "input": input, "words": words }),
+            ))
+        }
+        Command::Ordinal => {
+            let number: i64 = trimmed.parse().map_err(|_| "not an integer".to_string())?;
+            let words = if args.digits {
+                to_ordinal(number)
+            } else {
+                to_words_ordinal(number, &args.options)
+            };
+            Ok(output(
+                words.clone(),
+                json!({ "input": input, "ordinal": words }),
+            ))
+        }
+        Command::Parse if args.exact => {
+            let value = parse_exact_with(input, args.mode)
+                .map_err(|e| e.to_string())?
+                .to_string();
+            Ok(output(
+                value.clone(),
+                json!({ "input": input, "value": value }),
+            ))
+        }
+        Command::Parse => {
+            let value = from_words_with(input, args.mode).map_err(|e| e.to_string())?;
+            // JSON has no NaN or infinity ("1e400", "nan").
+            if !value.is_finite() {
+                return Err("not a finite number".to_string());
+            }
+            Ok(output(
+                value.to_string(),
+                json!({ "input": input, "value": value }),
+            ))
+        }
+        Command::Tokenise => {
+            let tokens = tokenise(input);
+            let text = tokens
+                .iter()
+                .map(|span| {
+                    let (kind, text) = token_parts(&span.token);
+                    format!("{}\t{}\t{}\t{}", span.start, span.end, kind, text)
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let json_tokens: Vec<Value> = tokens
+                .iter()
+                .map(|span| {
+                    let (kind, text) = token_parts(&span.token);
+                    json!({ "type": kind, "text": text, "start": span.start, "end": span.end })
+                })
+                .collect();
+            Ok(output(
+                text,
+                json!({ "input": input, "tokens": json_tokens }),
+            ))
+        }
+        Command::Units => {
+            let def = resolve_unit(trimmed, UnitCase::Insensitive)
+                .ok_or_else(|| "unknown unit".to_string())?
+                .def;
+            let plural = def.plural.clone().unwrap_or_else(|| to_plural(&def.name));
+            let unit_type = format!("{:?}", def.unit_type);
+            Ok(output(
+                format!("{}\t{}\t{}\t{}", def.symbol(), def.name, plural, unit_type),
+                json!({
+                    "input": input,
+                    "abbreviation": def.symbol(),
+                    "name": def.name,
+                    "plural": plural,
+                    "type": unit_type,
+                }),
+            ))
+        }
+    }
+}
+
+fn token_parts(token: &Token) -> (&'static str, &str) {
+    match token {
+        Token::NumberWord(s) => ("NumberWord", s),
+        Token::NumberString(s) => ("NumberString", s),
+        Token::Unit(s) => ("Unit", s),
+        Token::Unknown(s) => ("Unknown", s),
+    }
+}
@@ -1,4 +1,7 @@
+use crate::options::{CaseWriter, WithOptions};
 use crate::Options;
+use alloc::string::String;
+use core::fmt::{self, Write};
 
 pub const ONES: [&str; 20] = [
     "",
@@ -42,112 +45,177 @@ pub const SCALES: [&str; 12] = [
     "decillion",
 ];
 
-fn convert_less_than_one_thousand(n: u16, options: &Options) -> String {
-    if n == 0 {
-        return "".to_string();
-    }
-    if n < 20 {
-        return ONES[n as usize].to_string();
-    }
-    if n < 100 {
-        let ten = n / 10;
-        let rem = n % 10;
-        if rem == 0 {
-            TENS[ten as usize].to_string()
-        } else {
-            format!("{}-{}", TENS[ten as usize], ONES[rem as usize])
-        }
-    } else {
-        let hundred = n / 100;
-        let rem = n % 100;
-        let and = if options.use_and && rem > 0 {
-            " and "
-        } else {
-            " "
-        };
-        if rem == 0 {
-            format!("{} hundred", ONES[hundred as usize])
-        } else {
-            format!(
-                "{} hundred{}{}",
-                ONES[hundred as usize],
-                and,
-                convert_less_than_one_thousand(rem, options)
-            )
+/// Largest value written in words; anything bigger is kept as digits.
+const WORDS_LIMIT: u128 = 1000u128.pow(SCALES.len() as u32);
+
+/// Displays a number in words, as `to_words` does.
+///
+/// ```
+/// use numlang::{Case, Options, Words};
+///
+/// assert_eq!(format!("{} tablets", Words(42)), "forty-two tablets");
+/// let opts = Options::new().case(Case::TitleCase);
+/// assert_eq!(format!("{}", Words(21).with(&opts)), "Twenty-One");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Words<N>(pub N);
+
+impl<N: Into<f64> + Copy> Words<N> {
+    pub fn with(self, options: &Options) -> WithOptions<'_, Self> {
+        WithOptions {
+            value: self,
+            options,
         }
     }
 }
 
-fn convert_decimal_part(decimal: &str) -> String {
-    decimal
-        .chars()
-        .filter_map(|c| c.to_digit(10))
-        .map(|d| if d == 0 { "zero" } else { ONES[d as usize] })
-        .collect::<Vec<_>>()
-        .join(" ")
+impl<N: Into<f64> + Copy> fmt::Display for Words<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_words(f, self.0, &Options::new())
+    }
+}
+
+impl<N: Into<f64> + Copy> fmt::Display for WithOptions<'_, Words<N>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_words(f, self.value.0, self.options)
+    }
 }
 
+/// Converts a number to words. NaN is written as "not a number" and the
+/// infinities as "infinity" and "negative infinity".
 pub fn to_words(number: impl Into<f64>, options: &Options) -> String {
-    let num = number.into();
-    let mut prefix = String::new();
-    let mut abs_num = num;
+    let mut words = String::new();
+    write_words(&mut words, number, options).expect("writing to a String can't fail");
+    words
+}
+
+/// Writes a number in words to `w`, as `to_words` does, without allocating.
+pub fn write_words<W: fmt::Write>(
+    w: &mut W,
+    number: impl Into<f64>,
+    options: &Options,
+) -> fmt::Result {
+    let mut w = CaseWriter::new(w, options.case);
+    write_cardinal(&mut w, number.into(), options)?;
+    if options.append_only {
+        w.write_str(" only")?;
+    }
+    w.finish()
+}
+
+/// Writes a number in words, ignoring `append_only` and `case`.
+fn write_cardinal<W: fmt::Write>(w: &mut W, num: f64, options: &Options) -> fmt::Result {
+    if num.is_nan() {
+        return w.write_str("not a number");
+    }
     if num < 0.0 {
-        prefix = "negative ".to_string();
-        abs_num = -num;
-    }
-
-    // Convert to string to preserve decimal precision
-    let num_str = format!("{}", abs_num);
-    let parts: Vec<&str> = num_str.split('.').collect();
-    let int_part = parts[0];
-    let decimal_part = if parts.len() > 1 { parts[1] } else { "" };
-
-    let int_num: i64 = int_part.parse().unwrap_or(0);
-
-    let mut words = if int_num == 0 {
-        "zero".to_string()
-    } else {
-        let mut n = int_num;
-        let mut scale = 0;
-        let mut chunks = Vec::new();
-        while n > 0 {
-            let chunk = (n % 1000) as u16;
-            if chunk > 0 {
-                let mut chunk_str = convert_less_than_one_thousand(chunk, options);
-                if !SCALES[scale].is_empty() {
-                    chunk_str.push(' ');
-                    chunk_str.push_str(SCALES[scale]);
-                }
-                chunks.push(chunk_str);
-            }
-            n /= 1000;
-            scale += 1;
-        }
-        chunks.reverse();
-        if options.use_commas {
-            chunks.join(", ")
-        } else {
-            chunks.join(" ")
-        }
-    };
+        w.write_str("negative ")?;
+    }
+    if num.is_infinite() {
+        return w.write_str("infinity");
+    }
+    // The shortest decimal form, to keep the written precision.
+    let mut digits = DigitBuf::new();
+    write!(digits, "{}", num.abs())?;
+    let (int_part, decimal_part) = digits
+        .as_str()
+        .split_once('.')
+        .unwrap_or((digits.as_str(), ""));
+
+    match int_part.parse::<u128>() {
+        Ok(n) if n < WORDS_LIMIT => write_integer(w, n, options)?,
+        // Past the largest scale there are no words; keep the digits.
+        _ => w.write_str(int_part)?,
+    }
 
     if !decimal_part.is_empty() {
-        words.push_str(" point ");
-        words.push_str(&convert_decimal_part(decimal_part));
+        w.write_str(" point")?;
+        for d in decimal_part.chars().filter_map(|c| c.to_digit(10)) {
+            w.write_char(' ')?;
+            w.write_str(if d == 0 { "zero" } else { ONES[d as usize] })?;
+        }
     }
+    Ok(())
+}
 
-    let mut result = prefix + &words;
-    if options.append_only {
-        result.push_str(" only");
+/// Writes a whole number below `WORDS_LIMIT` in words.
+pub(crate) fn write_integer<W: fmt::Write>(w: &mut W, n: u128, options: &Options) -> fmt::Result {
+    if n == 0 {
+        return w.write_str("zero");
+    }
+    let mut first = true;
+    for scale in (0..SCALES.len()).rev() {
+        let chunk = (n / 1000u128.pow(scale as u32) % 1000) as u16;
+        if chunk == 0 {
+            continue;
+        }
+        if !first {
+            w.write_str(if options.use_commas { ", " } else { " " })?;
+        }
+        first = false;
+        write_less_than_one_thousand(w, chunk, options)?;
+        if scale > 0 {
+            w.write_char(' ')?;
+            w.write_str(SCALES[scale])?;
+        }
+    }
+    Ok(())
+}
+
+fn write_less_than_one_thousand<W: fmt::Write>(
+    w: &mut W,
+    n: u16,
+    options: &Options,
+) -> fmt::Result {
+    if n >= 100 {
+        w.write_str(ONES[n as usize / 100])?;
+        w.write_str(" hundred")?;
+        let rem = n % 100;
+        if rem == 0 {
+            return Ok(());
+        }
+        w.write_str(if options.use_and { " and " } else { " " })?;
+        return write_less_than_one_thousand(w, rem, options);
+    }
+    if n < 20 {
+        return w.write_str(ONES[n as usize]);
     }
-    if options.capitalize && !result.is_empty() {
-        let mut chars = result.chars();
-        if let Some(first) = chars.next() {
-            result = first.to_uppercase().collect::<String>() + chars.as_str();
+    w.write_str(TENS[n as usize / 10])?;
+    match ONES[n as usize % 10] {
+        "" => Ok(()),
+        ones => write!(w, "-{}", ones),
+    }
+}
+
+/// Holds a formatted `f64` on the stack. The longest, the smallest
+/// subnormal written out in full, is 326 bytes.
+struct DigitBuf {
+    buf: [u8; 400],
+    len: usize,
+}
+
+impl DigitBuf {
+    fn new() -> Self {
+        DigitBuf {
+            buf: [0; 400],
+            len: 0,
         }
     }
-    if options.uppercase {
-        result = result.to_uppercase();
+
+    fn as_str(&self) -> &str {
+        // Only whole `&str`s are copied in.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for DigitBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
     }
-    result
 }
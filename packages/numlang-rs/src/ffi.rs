@@ -0,0 +1,247 @@
+//! C ABI bindings, declared in `include/numlang.h`. Keep the two in step:
+//! the C harness in `tests/ffi` is compiled against the header and checks
+//! its struct layouts match these.
+//!
+//! Strings and token lists returned here are owned by the caller and must be
+//! released with `numlang_string_free` and `numlang_tokens_free`.
+
+use crate::tokenise::Token;
+use crate::{from_words_with, to_ordinal, to_words, to_words_ordinal, tokenise};
+use crate::{Case, Options, ParseErrorKind, ParseMode};
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+/// Result of a fallible call. Parse errors have one status per
+/// `ParseErrorKind`; the message has the detail.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    NullArgument = 1,
+    InvalidUtf8 = 2,
+    /// A word that isn't part of a number.
+    UnknownWord = 3,
+    /// A non-digit after "point".
+    InvalidDecimal = 4,
+    Overflow = 5,
+    /// Strict mode: number words in an order that isn't a number.
+    Malformed = 6,
+}
+
+/// `Options` for C. All zeros gives the defaults. The flags are bytes
+/// rather than `bool`, so any nonzero value from C is read as true.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct COptions {
+    pub use_commas: u8,
+    pub use_and: u8,
+    pub append_only: u8,
+    /// One of the `NUMLANG_CASE_*` values: 0 preserve, 1 lower, 2 upper,
+    /// 3 sentence, 4 title.
+    pub letter_case: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CTokenKind {
+    NumberWord = 0,
+    NumberString = 1,
+    Unit = 2,
+    Unknown = 3,
+}
+
+/// A token with its byte positions in the input.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CTokenSpan {
+    pub kind: CTokenKind,
+    pub text: *mut c_char,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct CTokens {
+    pub spans: *mut CTokenSpan,
+    pub len: usize,
+}
+
+/// Converts C options, or None if `letter_case` is out of range.
+///
+/// # Safety
+/// `options` is null or points to a valid `COptions`.
+unsafe fn options(options: *const COptions) -> Option<Options> {
+    let options = options.as_ref().copied().unwrap_or_default();
+    let case = match options.letter_case {
+        0 => Case::Preserve,
+        1 => Case::Lower,
+        2 => Case::Upper,
+        3 => Case::SentenceCase,
+        4 => Case::TitleCase,
+        _ => return None,
+    };
+    Some(
+        Options::new()
+            .use_commas(options.use_commas != 0)
+            .use_and(options.use_and != 0)
+            .append_only(options.append_only != 0)
+            .case(case),
+    )
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    // Generated text never contains NUL.
+    CString::new(s).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+fn parse_status(kind: &ParseErrorKind) -> Status {
+    match kind {
+        ParseErrorKind::UnknownWord(_) => Status::UnknownWord,
+        ParseErrorKind::InvalidDecimal(_) => Status::InvalidDecimal,
+        ParseErrorKind::Overflow => Status::Overflow,
+        ParseErrorKind::Malformed(_) => Status::Malformed,
+    }
+}
+
+/// `to_words`. Returns null if `letter_case` is out of range. NaN and
+/// infinities are written as "not a number" and "infinity".
+///
+/// # Safety
+/// `options` is null (for the defaults) or points to a valid `COptions`.
+#[no_mangle]
+pub unsafe extern "C" fn numlang_to_words(number: f64, options: *const COptions) -> *mut c_char {
+    match self::options(options) {
+        Some(options) => into_c_string(to_words(number, &options)),
+        None => ptr::null_mut(),
+    }
+}
+
+/// `to_words_ordinal`. Returns null if `letter_case` is out of range.
+///
+/// # Safety
+/// `options` is null (for the defaults) or points to a valid `COptions`.
+#[no_mangle]
+pub unsafe extern "C" fn numlang_to_words_ordinal(
+    number: i64,
+    options: *const COptions,
+) -> *mut c_char {
+    match self::options(options) {
+        Some(options) => into_c_string(to_words_ordinal(number, &options)),
+        None => ptr::null_mut(),
+    }
+}
+
+/// `to_ordinal` ("21st").
+#[no_mangle]
+pub extern "C" fn numlang_to_ordinal(number: i64) -> *mut c_char {
+    into_c_string(to_ordinal(number))
+}
+
+/// `from_words_with`, in strict mode if `strict` is set. On success the
+/// number is stored in `value`. On failure, if `error` isn't null, it
+/// receives the message.
+///
+/// # Safety
+/// `text` is null or a NUL-terminated string; `value` is null or writable;
+/// `error` is null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn numlang_from_words(
+    text: *const c_char,
+    strict: bool,
+    value: *mut f64,
+    error: *mut *mut c_char,
+) -> Status {
+    if text.is_null() || value.is_null() {
+        return Status::NullArgument;
+    }
+    let Ok(text) = CStr::from_ptr(text).to_str() else {
+        return Status::InvalidUtf8;
+    };
+    let mode = if strict {
+        ParseMode::Strict
+    } else {
+        ParseMode::Lenient
+    };
+    match from_words_with(text, mode) {
+        Ok(number) => {
+            *value = number;
+            Status::Ok
+        }
+        Err(err) => {
+            if !error.is_null() {
+                *error = into_c_string(err.to_string());
+            }
+            parse_status(&err.kind)
+        }
+    }
+}
+
+/// `tokenise`. On success `tokens` holds the spans, to be released with
+/// `numlang_tokens_free`.
+///
+/// # Safety
+/// `text` is null or a NUL-terminated string; `tokens` is null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn numlang_tokenise(text: *const c_char, tokens: *mut CTokens) -> Status {
+    if text.is_null() || tokens.is_null() {
+        return Status::NullArgument;
+    }
+    let Ok(text) = CStr::from_ptr(text).to_str() else {
+        return Status::InvalidUtf8;
+    };
+    let spans: Box<[CTokenSpan]> = tokenise(text)
+        .into_iter()
+        .map(|span| {
+            let (kind, text) = match span.token {
+                Token::NumberWord(s) => (CTokenKind::NumberWord, s),
+                Token::NumberString(s) => (CTokenKind::NumberString, s),
+                Token::Unit(s) => (CTokenKind::Unit, s),
+                Token::Unknown(s) => (CTokenKind::Unknown, s),
+            };
+            CTokenSpan {
+                kind,
+                text: into_c_string(text),
+                start: span.start,
+                end: span.end,
+            }
+        })
+        .collect();
+    let len = spans.len();
+    *tokens = CTokens {
+        spans: Box::into_raw(spans).cast(),
+        len,
+    };
+    Status::Ok
+}
+
+/// Releases a string returned by numlang. Null is ignored.
+///
+/// # Safety
+/// `s` is null or a string from numlang that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn numlang_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Releases the spans filled in by `numlang_tokenise` and empties `tokens`.
+///
+/// # Safety
+/// `tokens` is null or was filled in by `numlang_tokenise` and not since
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn numlang_tokens_free(tokens: *mut CTokens) {
+    let Some(tokens) = tokens.as_mut() else {
+        return;
+    };
+    if !tokens.spans.is_null() {
+        let spans = Box::from_raw(ptr::slice_from_raw_parts_mut(tokens.spans, tokens.len));
+        for span in spans.iter() {
+            numlang_string_free(span.text);
+        }
+    }
+    tokens.spans = ptr::null_mut();
+    tokens.len = 0;
+}
@@ -0,0 +1,234 @@
+use crate::cardinal::SCALES;
+use crate::parse::{from_words, FRACTION_WORDS};
+use crate::quantity::FRACTIONS;
+use crate::string::from_string;
+use crate::tokenise::{tokenise, Token, TokenSpan};
+use std::ops::Range;
+
+/// A number found in tokenised text.
+pub(crate) struct NumberPhrase {
+    pub value: f64,
+    /// Indexes of the tokens making up the number.
+    pub tokens: Range<usize>,
+    /// True if written in words ("two and a half") rather than digits.
+    pub spelled: bool,
+}
+
+/// Finds every number in `text` and returns each value with its byte range.
+///
+/// Each maximal number phrase is evaluated on its own: digits ("2.5",
+/// "1,000"), number words ("two hundred and five", "one million, two
+/// hundred thousand"), fractions in words ("one and a half", "three
+/// quarters") and in digits ("1/2", "1½").
+///
+/// ```
+/// use numlang::find_numbers;
+///
+/// let text = "give two tablets then one";
+/// let found = find_numbers(text);
+/// assert_eq!(found, vec![(2.0, 5..8), (1.0, 22..25)]);
+/// ```
+pub fn find_numbers(text: &str) -> Vec<(f64, Range<usize>)> {
+    let tokens = tokenise(text);
+    number_phrases(text, &tokens)
+        .into_iter()
+        .map(|p| {
+            (
+                p.value,
+                tokens[p.tokens.start].start..tokens[p.tokens.end - 1].end,
+            )
+        })
+        .collect()
+}
+
+/// Groups `tokens` (from `tokenise(text)`) into numbers, in order.
+pub(crate) fn number_phrases(text: &str, tokens: &[TokenSpan]) -> Vec<NumberPhrase> {
+    let mut phrases = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        match phrase_at(text, tokens, i) {
+            Some(phrase) => {
+                i = phrase.tokens.end;
+                phrases.push(phrase);
+            }
+            None => i += 1,
+        }
+    }
+    phrases
+}
+
+fn phrase_at(text: &str, tokens: &[TokenSpan], start: usize) -> Option<NumberPhrase> {
+    let digits = |value| NumberPhrase {
+        value,
+        tokens: start..start + 1,
+        spelled: false,
+    };
+    let end = match &tokens[start].token {
+        Token::NumberString(s) => return from_string(s).ok().map(digits),
+        Token::Unknown(s) => {
+            if let Some(value) = digit_fraction(s).or_else(|| grouped_digits(s)) {
+                return Some(digits(value));
+            }
+            // "a half", "a quarter"
+            if !s.eq_ignore_ascii_case("a") || !is_fraction(next_word(text, tokens, start + 1)?) {
+                return None;
+            }
+            start + 2
+        }
+        Token::NumberWord(w) if !w.eq_ignore_ascii_case("and") => {
+            let end = number_word_run(text, tokens, start);
+            if tokens[start..end].iter().all(|t| is_filler(&t.token)) {
+                return None;
+            }
+            fraction_tail(text, tokens, end)
+        }
+        Token::NumberWord(_) | Token::Unit(_) => return None,
+    };
+    let phrase = &text[tokens[start].start..tokens[end - 1].end];
+    from_words(phrase).ok().map(|value| NumberPhrase {
+        value,
+        tokens: start..end,
+        spelled: true,
+    })
+}
+
+/// Index just past the run of number words starting at `start`. Runs are
+/// broken by anything other than whitespace, and by "and" unless it follows
+/// a scale ("two hundred and five" is one number, "one and two" is two).
+/// A comma after a scale continues the run when the next group is smaller,
+/// as `to_words` writes with `use_commas` ("one million, two hundred
+/// thousand").
+fn number_word_run(text: &str, tokens: &[TokenSpan], start: usize) -> usize {
+    let mut end = start + 1;
+    loop {
+        if end < tokens.len()
+            && matches!(&tokens[end].token, Token::NumberWord(w)
+                if !w.eq_ignore_ascii_case("and") || is_scale(&tokens[end - 1].token))
+            && only_space_between(text, &tokens[end - 1], &tokens[end])
+        {
+            end += 1;
+        } else if comma_continues(text, tokens, end) {
+            end += 2;
+        } else {
+            break;
+        }
+    }
+    while end > start + 1 && is_filler(&tokens[end - 1].token) {
+        end -= 1;
+    }
+    end
+}
+
+/// True if the token at `comma` is a comma joining the scale before it to a
+/// smaller group of number words after it.
+fn comma_continues(text: &str, tokens: &[TokenSpan], comma: usize) -> bool {
+    let Some(Token::Unknown(c)) = tokens.get(comma).map(|t| &t.token) else {
+        return false;
+    };
+    let Some(before) = scale_rank(&tokens[comma - 1].token) else {
+        return false;
+    };
+    if c != "," || tokens[comma - 1].end != tokens[comma].start {
+        return false;
+    }
+    let mut next = comma + 1;
+    while next < tokens.len()
+        && matches!(&tokens[next].token, Token::NumberWord(_))
+        && only_space_between(text, &tokens[next - 1], &tokens[next])
+    {
+        if scale_rank(&tokens[next].token).is_some_and(|rank| rank >= before) {
+            return false;
+        }
+        next += 1;
+    }
+    next > comma + 1 && !is_filler(&tokens[comma + 1].token)
+}
+
+/// Extends a run of number words ending at `end` over a following fraction:
+/// "three quarters", "one and a half", "two and three quarters".
+fn fraction_tail(text: &str, tokens: &[TokenSpan], end: usize) -> usize {
+    if next_word(text, tokens, end).is_some_and(is_fraction) {
+        return end + 1;
+    }
+    let and_a_fraction = next_word(text, tokens, end)
+        .is_some_and(|w| w.eq_ignore_ascii_case("and"))
+        && next_word(text, tokens, end + 1).is_some_and(|w| {
+            w.eq_ignore_ascii_case("a") || matches!(tokens[end + 1].token, Token::NumberWord(_))
+        })
+        && next_word(text, tokens, end + 2).is_some_and(is_fraction);
+    if and_a_fraction {
+        end + 3
+    } else {
+        end
+    }
+}
+
+/// Text of the token at `idx` if only whitespace separates it from the
+/// previous token.
+fn next_word<'a>(text: &'a str, tokens: &[TokenSpan], idx: usize) -> Option<&'a str> {
+    let token = tokens.get(idx)?;
+    (idx > 0 && only_space_between(text, &tokens[idx - 1], token))
+        .then(|| &text[token.start..token.end])
+}
+
+fn is_fraction(word: &str) -> bool {
+    FRACTION_WORDS
+        .iter()
+        .any(|(w, _)| word.eq_ignore_ascii_case(w))
+}
+
+/// Number words that aren't a number on their own.
+fn is_filler(token: &Token) -> bool {
+    matches!(token, Token::NumberWord(w)
+        if ["and", "point", "negative"].iter().any(|f| w.eq_ignore_ascii_case(f)))
+}
+
+fn is_scale(token: &Token) -> bool {
+    matches!(token, Token::NumberWord(w) if w.eq_ignore_ascii_case("hundred"))
+        || scale_rank(token).is_some()
+}
+
+/// Position in `SCALES` of a scale word from "thousand" up.
+fn scale_rank(token: &Token) -> Option<usize> {
+    let Token::NumberWord(w) = token else {
+        return None;
+    };
+    (1..SCALES.len()).find(|&i| w.eq_ignore_ascii_case(SCALES[i]))
+}
+
+pub(crate) fn only_space_between(text: &str, a: &TokenSpan, b: &TokenSpan) -> bool {
+    text[a.end..b.start].chars().all(char::is_whitespace)
+}
+
+/// Parses digits grouped in thousands with commas ("1,000", "-12,345.5").
+fn grouped_digits(s: &str) -> Option<f64> {
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let (first, rest) = whole.split_once(',')?;
+    let digits = |g: &str| g.chars().all(|c| c.is_ascii_digit());
+    let grouped = (1..=3).contains(&first.len())
+        && digits(first)
+        && rest.split(',').all(|g| g.len() == 3 && digits(g))
+        && digits(fraction);
+    if !grouped {
+        return None;
+    }
+    from_string(&s.replace(',', "")).ok()
+}
+
+/// Parses "1/2", "3/4", "½" and "1½".
+fn digit_fraction(s: &str) -> Option<f64> {
+    if let Some((num, den)) = s.split_once('/') {
+        let num: u64 = num.parse().ok()?;
+        let den: u64 = den.parse().ok()?;
+        return (den != 0).then(|| num as f64 / den as f64);
+    }
+    let (whole, fraction) = FRACTIONS.iter().find_map(|&(num, den, glyph)| {
+        s.strip_suffix(glyph)
+            .map(|whole| (whole, num as f64 / den as f64))
+    })?;
+    if whole.is_empty() {
+        return Some(fraction);
+    }
+    whole.parse::<u64>().ok().map(|w| w as f64 + fraction)
+}
@@ -1,22 +1,76 @@
+//! Without the default `std` feature, the crate builds for `no_std` targets
+//! with `alloc`, keeping the number converters (`to_words`,
+//! `to_words_ordinal`, `from_words` and friends). Tokenising, units,
+//! quantities and text processing need `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 mod options;
-pub use options::Options;
+pub use options::{Case, Options, WithOptions};
 
 pub mod cardinal;
-pub use cardinal::to_words;
-pub use ordinal::{to_ordinal, to_words_ordinal};
+pub use cardinal::{to_words, write_words, Words};
+pub use ordinal::{to_ordinal, to_words_ordinal, write_words_ordinal, Ordinal};
 pub mod ordinal;
 
+#[cfg(feature = "std")]
 pub mod tokenise;
-pub use tokenise::{tokenise, Token};
+#[cfg(feature = "std")]
+pub use tokenise::{tokenise, tokenise_with, Token};
 
 pub mod parse;
-pub use parse::from_words;
+pub use parse::{from_words, from_words_with, parse_exact, ParseError, ParseErrorKind, ParseMode};
+
+pub mod number;
+pub use number::Number;
 
 mod string;
 pub use string::from_string;
 
+#[cfg(feature = "std")]
+mod si;
+#[cfg(feature = "std")]
 pub mod unit;
-pub use unit::{abbreviate_unit, expand_unit, unit_type, UnitType};
+#[cfg(feature = "std")]
+pub use unit::{
+    abbreviate_unit, aliases, expand_unit, register_units, resolve_unit, unit_type, UnitCase,
+    UnitMatch, UnitType,
+};
+
+#[cfg(feature = "std")]
+pub mod unit_file;
+#[cfg(feature = "std")]
+pub use unit_file::load_units;
+
+#[cfg(feature = "std")]
+pub mod quantity;
+#[cfg(feature = "std")]
+pub use quantity::{format_quantity, QuantityOptions};
 
+#[cfg(feature = "std")]
 pub mod plural;
+#[cfg(feature = "std")]
 pub use plural::{to_plural, to_singular};
+
+#[cfg(feature = "std")]
+pub mod article;
+#[cfg(feature = "std")]
+pub use article::indefinite_article;
+
+#[cfg(feature = "std")]
+pub mod normalise;
+#[cfg(feature = "std")]
+pub use normalise::{normalise_text, NormaliseOptions, NumberStyle, UnitStyle};
+
+#[cfg(feature = "std")]
+pub mod find;
+#[cfg(feature = "std")]
+pub use find::find_numbers;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
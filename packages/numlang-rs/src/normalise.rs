@@ -0,0 +1,170 @@
+use crate::cardinal::to_words;
+use crate::find::{number_phrases, only_space_between};
+use crate::parse::parse_exact;
+use crate::plural::to_plural;
+use crate::quantity::{inflect_unit, takes_singular, unit_case};
+use crate::tokenise::{tokenise, Token};
+use crate::unit::unit_def;
+use crate::{Number, Options};
+
+/// Unit spellings that are usually ordinary words even after a number
+/// ("1 in 10"). They are only rewritten when attached to it ("5in").
+const WORD_UNITS: [&str; 1] = ["in"];
+
+/// How `normalise_text` writes numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberStyle {
+    /// Leave numbers as written.
+    #[default]
+    Keep,
+    /// Write numbers as digits ("twenty-one" -> "21"). Fractions without an
+    /// exact decimal are written as fractions ("one third" -> "1/3").
+    Digits,
+    /// Write numbers as words ("21" -> "twenty-one").
+    Words,
+}
+
+/// How `normalise_text` writes units that follow a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitStyle {
+    /// Leave units as written.
+    #[default]
+    Keep,
+    /// Write the unit's canonical abbreviation ("milligrams" -> "mg").
+    /// Abbreviations that are words are inflected to match the number
+    /// ("2 tablet" -> "2 tablets").
+    Abbreviation,
+    /// Write the unit's full name, singular or plural to match the number
+    /// ("2 ml" -> "2 milliliters").
+    Name,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NormaliseOptions {
+    pub numbers: NumberStyle,
+    pub units: UnitStyle,
+    /// Formatting options passed to `to_words` for `NumberStyle::Words`. Its
+    /// `case` also applies to unit names written for `UnitStyle::Name`.
+    pub words: Options,
+}
+
+/// Rewrites the numbers and units in running text, leaving everything else,
+/// including whitespace and punctuation, exactly as it was.
+///
+/// Numbers are found as by [`find_numbers`](crate::find_numbers), so runs of
+/// number words ("two hundred and five") are read as one number.
+/// Units are only rewritten directly after a number, so ordinary words that
+/// are also unit spellings ("drop", "min") are left alone elsewhere, and "in"
+/// only counts as a unit when attached to the number ("5in"). Numbers that
+/// can't be evaluated ("1-2") are kept as written.
+pub fn normalise_text(text: &str, options: &NormaliseOptions) -> String {
+    let tokens = tokenise(text);
+    let mut phrases = number_phrases(text, &tokens).into_iter().peekable();
+    let mut out = String::with_capacity(text.len());
+    let mut copied = 0;
+    // Value of the number just before the current token, if adjacent.
+    let mut previous: Option<f64> = None;
+    let mut i = 0;
+    while i < tokens.len() {
+        let span = &tokens[i];
+        let phrase = phrases.next_if(|p| p.tokens.start == i);
+        let (end, replacement, value) = match (phrase, &span.token) {
+            (Some(phrase), token) => {
+                let replacement = match options.numbers {
+                    NumberStyle::Words if matches!(token, Token::NumberString(_)) => {
+                        Some(to_words(phrase.value, &options.words))
+                    }
+                    NumberStyle::Digits if phrase.spelled => {
+                        let words = &text[span.start..tokens[phrase.tokens.end - 1].end];
+                        Some(digits(words, phrase.value))
+                    }
+                    _ => None,
+                };
+                (phrase.tokens.end, replacement, Some(phrase.value))
+            }
+            (None, Token::Unit(unit))
+                if !WORD_UNITS.contains(&unit.to_lowercase().as_str())
+                    || (i > 0 && tokens[i - 1].end == span.start) =>
+            {
+                let replacement = previous.and_then(|v| rewrite_unit(unit, v, options));
+                (i + 1, replacement, None)
+            }
+            // Full unit names aren't unit tokens ("5 milligrams").
+            (None, Token::Unknown(word)) if previous.is_some() && unit_def(word).is_some() => {
+                let replacement = previous.and_then(|v| rewrite_unit(word, v, options));
+                (i + 1, replacement, None)
+            }
+            (None, _) => (i + 1, None, None),
+        };
+        let unit_abuts = end < tokens.len()
+            && tokens[end - 1].end == tokens[end].start
+            && matches!(tokens[end].token, Token::Unit(_));
+        if let Some(replacement) = replacement {
+            out.push_str(&text[copied..span.start]);
+            // Words need a space where digits didn't: "200g" -> "200 grams".
+            let is_word = |s: &str| s.ends_with(char::is_alphabetic);
+            if value.is_none()
+                && is_word(&replacement)
+                && !out.is_empty()
+                && !out.ends_with(char::is_whitespace)
+            {
+                out.push(' ');
+            }
+            out.push_str(&replacement);
+            if value.is_some() && is_word(&replacement) && unit_abuts {
+                out.push(' ');
+            }
+            copied = tokens[end - 1].end;
+        }
+        previous = value.filter(|_| {
+            end < tokens.len() && only_space_between(text, &tokens[end - 1], &tokens[end])
+        });
+        i = end;
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+fn rewrite_unit(unit: &str, value: f64, options: &NormaliseOptions) -> Option<String> {
+    match options.units {
+        UnitStyle::Keep => None,
+        UnitStyle::Abbreviation => {
+            let def = unit_def(unit)?;
+            // `to_plural` leaves abbreviations that aren't words ("mg") alone.
+            Some(if takes_singular(value) {
+                def.symbol().to_string()
+            } else {
+                to_plural(def.symbol())
+            })
+        }
+        UnitStyle::Name => {
+            unit_def(unit)?;
+            let name = inflect_unit(unit, takes_singular(value), true);
+            Some(unit_case(name, options.words.case))
+        }
+    }
+}
+
+/// Writes a number phrase as digits, keeping fractions that have no exact
+/// decimal ("one third") as fractions ("1/3").
+fn digits(words: &str, value: f64) -> String {
+    match parse_exact(words) {
+        Ok(
+            number @ Number::Rational {
+                mut denominator, ..
+            },
+        ) => {
+            for factor in [2, 5] {
+                while denominator % factor == 0 {
+                    denominator /= factor;
+                }
+            }
+            if denominator == 1 {
+                value.to_string()
+            } else {
+                number.to_string()
+            }
+        }
+        _ => value.to_string(),
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::format;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// An exact number, as returned by [`parse_exact`](crate::parse_exact).
+///
+/// Convert with [`Number::to_f64`] (or `f64::from`) when an approximation
+/// is fine; keep the exact form when the value will be scaled further
+/// ("two thirds" of a tablet times a pack count).
+///
+/// Equality and hashing go by value, so "0.5", "0.50" and "one half" are
+/// equal even though they display differently.
+#[derive(Debug, Clone, Copy)]
+pub enum Number {
+    Integer(i128),
+    /// `numerator / denominator` in lowest terms, with a denominator > 1.
+    Rational {
+        numerator: i128,
+        denominator: i128,
+    },
+    /// `digits / 10^scale`, keeping the written precision ("12.50" is 1250
+    /// with scale 2).
+    Decimal {
+        digits: i128,
+        scale: u32,
+    },
+}
+
+impl Number {
+    /// Builds a rational in lowest terms, or an integer if it divides evenly.
+    /// Returns None if `denominator` is zero or the result doesn't fit in
+    /// `i128` (`i128::MIN / -1`).
+    pub fn rational(numerator: i128, denominator: i128) -> Option<Number> {
+        let (numerator, denominator) = reduce(numerator, denominator)?;
+        Some(if denominator == 1 {
+            Number::Integer(numerator)
+        } else {
+            Number::Rational {
+                numerator,
+                denominator,
+            }
+        })
+    }
+
+    pub fn to_f64(self) -> f64 {
+        match self {
+            Number::Integer(n) => n as f64,
+            Number::Rational {
+                numerator,
+                denominator,
+            } => numerator as f64 / denominator as f64,
+            // Parsing the decimal text rounds correctly, unlike dividing.
+            Number::Decimal { digits, scale } => {
+                format!("{}e-{}", digits, scale).parse().unwrap_or(f64::NAN)
+            }
+        }
+    }
+
+    /// The value in a form that is the same for equal numbers.
+    fn key(self) -> Key {
+        match self {
+            Number::Integer(n) => Key::Fraction(n, 1),
+            Number::Rational {
+                numerator,
+                denominator,
+            } => {
+                if denominator == 0 {
+                    return Key::Fraction(numerator.signum(), 0);
+                }
+                // `Rational` can be built directly, so it may not be reduced.
+                let (n, d) = reduce(numerator, denominator).unwrap_or((numerator, denominator));
+                Key::Fraction(n, d)
+            }
+            Number::Decimal {
+                mut digits,
+                mut scale,
+            } => {
+                while scale > 0 && digits % 10 == 0 {
+                    digits /= 10;
+                    scale -= 1;
+                }
+                // digits / (2^scale * 5^scale), with common factors cancelled.
+                let (mut n, mut twos, mut fives) = (digits, scale, scale);
+                while twos > 0 && n % 2 == 0 {
+                    n /= 2;
+                    twos -= 1;
+                }
+                while fives > 0 && n % 5 == 0 {
+                    n /= 5;
+                    fives -= 1;
+                }
+                let denominator = 2i128
+                    .checked_pow(twos)
+                    .zip(5i128.checked_pow(fives))
+                    .and_then(|(t, f)| t.checked_mul(f));
+                match denominator {
+                    Some(d) => Key::Fraction(n, d),
+                    // Too fine to equal any fraction of `i128`s.
+                    None => Key::Decimal(digits, scale),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn negate(self) -> Number {
+        match self {
+            Number::Integer(n) => Number::Integer(-n),
+            Number::Rational {
+                numerator,
+                denominator,
+            } => Number::Rational {
+                numerator: -numerator,
+                denominator,
+            },
+            Number::Decimal { digits, scale } => Number::Decimal {
+                digits: -digits,
+                scale,
+            },
+        }
+    }
+}
+
+/// `Number` reduced for comparison: a fraction in lowest terms with a
+/// positive denominator, or a decimal without trailing zeros.
+#[derive(PartialEq, Eq, Hash)]
+enum Key {
+    Fraction(i128, i128),
+    Decimal(i128, u32),
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Number) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Number {}
+
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl From<Number> for f64 {
+    fn from(number: Number) -> f64 {
+        number.to_f64()
+    }
+}
+
+/// Integers as digits, rationals as "2/3" and decimals with their scale
+/// ("12.50").
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Number::Integer(n) => write!(f, "{}", n),
+            Number::Rational {
+                numerator,
+                denominator,
+            } => write!(f, "{}/{}", numerator, denominator),
+            Number::Decimal { digits, scale } => {
+                let sign = if digits < 0 { "-" } else { "" };
+                let digits = format!(
+                    "{:0>width$}",
+                    digits.unsigned_abs(),
+                    width = scale as usize + 1
+                );
+                let (whole, fraction) = digits.split_at(digits.len() - scale as usize);
+                if fraction.is_empty() {
+                    write!(f, "{}{}", sign, whole)
+                } else {
+                    write!(f, "{}{}.{}", sign, whole, fraction)
+                }
+            }
+        }
+    }
+}
+
+/// `numerator / denominator` in lowest terms with a positive denominator.
+/// Returns None if `denominator` is zero or the result doesn't fit in `i128`.
+fn reduce(numerator: i128, denominator: i128) -> Option<(i128, i128)> {
+    if denominator == 0 {
+        return None;
+    }
+    let divisor = gcd(numerator, denominator);
+    let (n, d) = (
+        numerator.unsigned_abs() / divisor,
+        denominator.unsigned_abs() / divisor,
+    );
+    let n = if (numerator < 0) != (denominator < 0) {
+        0i128.checked_sub_unsigned(n)?
+    } else {
+        i128::try_from(n).ok()?
+    };
+    Some((n, i128::try_from(d).ok()?))
+}
+
+/// Greatest common divisor of the magnitudes, which may be 2^127.
+fn gcd(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    // Only zero when both are zero, which `reduce` rules out.
+    a.max(1)
+}
@@ -1,20 +1,271 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// Formatting options for `to_words` and `to_words_ordinal`.
+///
+/// Build with `Options::new()` and the setter methods; new settings may be
+/// added, so the struct can't be written as a literal outside this crate.
+///
+/// ```
+/// use numlang::{to_words, Case, Options};
+///
+/// let opts = Options::new().use_and(true).case(Case::SentenceCase);
+/// assert_eq!(to_words(123, &opts), "One hundred and twenty-three");
+/// ```
+///
+/// With the `serde` feature, options can be stored in config files; missing
+/// fields take their default values. Files written before `case` existed
+/// still load: `uppercase` becomes `Case::Upper` and `capitalize` becomes
+/// `Case::SentenceCase`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "StoredOptions"))]
+#[non_exhaustive]
 pub struct Options {
     pub use_commas: bool,
     pub use_and: bool,
     pub append_only: bool,
-    pub uppercase: bool,
-    pub capitalize: bool,
+    pub case: Case,
 }
 
-impl Default for Options {
-    fn default() -> Self {
+impl Options {
+    /// The default options: `Case::Preserve` (words as generated, in
+    /// lowercase), no commas, "and" or "only".
+    pub const fn new() -> Self {
         Options {
             use_commas: false,
             use_and: false,
             append_only: false,
-            uppercase: false,
-            capitalize: false,
+            case: Case::Preserve,
+        }
+    }
+
+    /// Separate scale groups with commas ("one thousand, two hundred").
+    pub const fn use_commas(mut self, use_commas: bool) -> Self {
+        self.use_commas = use_commas;
+        self
+    }
+
+    /// Put "and" after hundreds ("one hundred and five").
+    pub const fn use_and(mut self, use_and: bool) -> Self {
+        self.use_and = use_and;
+        self
+    }
+
+    /// End with "only", as on cheques ("twelve only").
+    pub const fn append_only(mut self, append_only: bool) -> Self {
+        self.append_only = append_only;
+        self
+    }
+
+    /// Letter case of the output ("Twenty-One" with `Case::TitleCase`).
+    pub const fn case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+}
+
+/// `Options` as stored, including the flags `case` replaced.
+#[cfg(feature = "serde")]
+#[derive(Default, serde::Deserialize)]
+#[serde(default)]
+struct StoredOptions {
+    use_commas: bool,
+    use_and: bool,
+    append_only: bool,
+    case: Option<Case>,
+    uppercase: bool,
+    capitalize: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<StoredOptions> for Options {
+    fn from(stored: StoredOptions) -> Self {
+        // As before, `uppercase` takes precedence over `capitalize`.
+        let legacy = if stored.uppercase {
+            Case::Upper
+        } else if stored.capitalize {
+            Case::SentenceCase
+        } else {
+            Case::Preserve
+        };
+        Options::new()
+            .use_commas(stored.use_commas)
+            .use_and(stored.use_and)
+            .append_only(stored.append_only)
+            .case(stored.case.unwrap_or(legacy))
+    }
+}
+
+/// Letter case for generated text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Case {
+    /// "twenty-one tablets"
+    Lower,
+    /// "TWENTY-ONE TABLETS"
+    Upper,
+    /// The first letter of each sentence: "Twenty-one tablets. Two doses."
+    SentenceCase,
+    /// Every word and each part of a hyphenated compound, except joining
+    /// words such as "and": "Two Hundred and Twenty-One".
+    TitleCase,
+    /// Text as generated: number words in lowercase, and units the registry
+    /// doesn't know in the case they were given ("Blister" -> "Blisters").
+    #[default]
+    Preserve,
+}
+
+/// Words left in lowercase by title case unless they come first.
+const MINOR_WORDS: [&str; 7] = ["a", "an", "and", "of", "or", "per", "the"];
+
+impl Case {
+    pub fn apply(self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut writer = CaseWriter::new(&mut out, self);
+        writer
+            .write_str(text)
+            .and_then(|_| writer.finish())
+            .expect("writing to a String can't fail");
+        out
+    }
+}
+
+/// A [`Words`](crate::Words) or [`Ordinal`](crate::Ordinal) displayed with
+/// the given options: `Words(42).with(&options)`.
+#[derive(Debug, Clone, Copy)]
+pub struct WithOptions<'a, T> {
+    pub(crate) value: T,
+    pub(crate) options: &'a Options,
+}
+
+/// Applies a `Case` to text as it is written, without buffering more than
+/// the start of a word that may be one of `MINOR_WORDS`. Call `finish` after
+/// the last write.
+pub(crate) struct CaseWriter<W> {
+    inner: W,
+    case: Case,
+    /// At the start of a sentence, or of a word or compound part in title
+    /// case.
+    at_start: bool,
+    /// The last character ended a sentence.
+    after_stop: bool,
+    /// Title case: a later word, which may be a minor word.
+    later_word: bool,
+    /// Title case: the letters so far of a word that may be a minor word.
+    pending: [u8; 3],
+    pending_len: usize,
+}
+
+impl<W: Write> CaseWriter<W> {
+    pub(crate) fn new(inner: W, case: Case) -> Self {
+        CaseWriter {
+            inner,
+            case,
+            at_start: true,
+            after_stop: false,
+            later_word: false,
+            pending: [0; 3],
+            pending_len: 0,
+        }
+    }
+
+    /// Writes out a held-back word.
+    pub(crate) fn finish(&mut self) -> fmt::Result {
+        self.flush(true)
+    }
+
+    /// Writes out the held-back letters, in lowercase if they are a whole
+    /// minor word.
+    fn flush(&mut self, whole_word: bool) -> fmt::Result {
+        let pending = self.pending;
+        let word = core::str::from_utf8(&pending[..self.pending_len]).unwrap_or_default();
+        self.pending_len = 0;
+        if whole_word && MINOR_WORDS.iter().any(|m| word.eq_ignore_ascii_case(m)) {
+            for c in word.chars() {
+                self.inner.write_char(c.to_ascii_lowercase())?;
+            }
+            Ok(())
+        } else {
+            self.title_part(word)
+        }
+    }
+
+    /// Writes a title-case word part, capitalised.
+    fn title_part(&mut self, part: &str) -> fmt::Result {
+        for c in part.chars() {
+            self.title_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn title_char(&mut self, c: char) -> fmt::Result {
+        if self.at_start && c.is_alphanumeric() {
+            self.at_start = false;
+            for upper in c.to_uppercase() {
+                self.inner.write_char(upper)?;
+            }
+            Ok(())
+        } else {
+            self.inner.write_char(c)
+        }
+    }
+
+    fn title_case(&mut self, c: char) -> fmt::Result {
+        if c == ' ' || c == '-' {
+            self.flush(c == ' ')?;
+            self.at_start = true;
+            // Only whole words can be minor words, not parts of compounds.
+            self.later_word = c == ' ';
+            return self.inner.write_char(c);
+        }
+        if self.later_word && self.pending_len < self.pending.len() && c.is_ascii() {
+            let mut candidate = self.pending;
+            candidate[self.pending_len] = c.to_ascii_lowercase() as u8;
+            let prefix = &candidate[..=self.pending_len];
+            if MINOR_WORDS.iter().any(|m| m.as_bytes().starts_with(prefix)) {
+                self.pending[self.pending_len] = c as u8;
+                self.pending_len += 1;
+                return Ok(());
+            }
+        }
+        self.later_word = false;
+        self.flush(false)?;
+        self.title_char(c)
+    }
+
+    fn sentence_case(&mut self, c: char) -> fmt::Result {
+        if self.after_stop && c.is_whitespace() {
+            self.at_start = true;
+        }
+        self.after_stop = matches!(c, '.' | '!' | '?');
+        if self.at_start && c.is_alphanumeric() {
+            self.at_start = false;
+            for upper in c.to_uppercase() {
+                self.inner.write_char(upper)?;
+            }
+            Ok(())
+        } else {
+            self.inner.write_char(c)
+        }
+    }
+}
+
+impl<W: Write> Write for CaseWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.case {
+            Case::Preserve => self.inner.write_str(s),
+            Case::Lower => s
+                .chars()
+                .flat_map(char::to_lowercase)
+                .try_for_each(|c| self.inner.write_char(c)),
+            Case::Upper => s
+                .chars()
+                .flat_map(char::to_uppercase)
+                .try_for_each(|c| self.inner.write_char(c)),
+            Case::SentenceCase => s.chars().try_for_each(|c| self.sentence_case(c)),
+            Case::TitleCase => s.chars().try_for_each(|c| self.title_case(c)),
         }
     }
 }
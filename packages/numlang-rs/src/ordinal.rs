@@ -1,5 +1,9 @@
-use crate::cardinal::to_words;
+use crate::cardinal::write_integer;
+use crate::options::{CaseWriter, WithOptions};
 use crate::Options;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{self, Write};
 
 const ORDINAL_SUFFIXES: [&str; 10] = ["th", "st", "nd", "rd", "th", "th", "th", "th", "th", "th"];
 
@@ -15,18 +19,6 @@ const ORDINAL_ONES: [(&str, &str); 9] = [
     ("nine", "ninth"),
 ];
 
-const ORDINAL_TENS: [(&str, &str); 8] = [
-    ("ten", "tenth"),
-    ("twenty", "twentieth"),
-    ("thirty", "thirtieth"),
-    ("forty", "fortieth"),
-    ("fifty", "fiftieth"),
-    ("sixty", "sixtieth"),
-    ("seventy", "seventieth"),
-    ("eighty", "eightieth"),
-    // ninety handled below
-];
-
 pub fn to_ordinal(number: impl Into<i64>) -> String {
     let num = number.into();
     if num < 0 {
@@ -41,78 +33,145 @@ pub fn to_ordinal(number: impl Into<i64>) -> String {
     }
 }
 
+/// Displays an integer as an ordinal in words, as `to_words_ordinal` does.
+///
+/// ```
+/// use numlang::{Options, Ordinal};
+///
+/// assert_eq!(format!("the {} dose", Ordinal(3)), "the third dose");
+/// let opts = Options::new().append_only(true);
+/// assert_eq!(Ordinal(21).with(&opts).to_string(), "twenty-first only");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ordinal<N>(pub N);
+
+impl<N: Into<i64> + Copy> Ordinal<N> {
+    pub fn with(self, options: &Options) -> WithOptions<'_, Self> {
+        WithOptions {
+            value: self,
+            options,
+        }
+    }
+}
+
+impl<N: Into<i64> + Copy> fmt::Display for Ordinal<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_words_ordinal(f, self.0, &Options::new())
+    }
+}
+
+impl<N: Into<i64> + Copy> fmt::Display for WithOptions<'_, Ordinal<N>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_words_ordinal(f, self.value.0, self.options)
+    }
+}
+
 pub fn to_words_ordinal(number: impl Into<i64>, options: &Options) -> String {
+    let mut words = String::new();
+    write_words_ordinal(&mut words, number, options).expect("writing to a String can't fail");
+    words
+}
+
+/// Writes an ordinal in words to `w`, as `to_words_ordinal` does, without
+/// allocating.
+pub fn write_words_ordinal<W: fmt::Write>(
+    w: &mut W,
+    number: impl Into<i64>,
+    options: &Options,
+) -> fmt::Result {
     let num = number.into();
+    let mut w = CaseWriter::new(w, options.case);
+    if num < 0 {
+        w.write_str("negative ")?;
+    }
     if num == 0 {
-        return "zeroth".to_string();
+        w.write_str("zeroth")?;
+    } else {
+        let mut last = LastWord::new(&mut w);
+        write_integer(&mut last, num.unsigned_abs() as u128, options)?;
+        last.finish()?;
     }
-    if num < 0 {
-        return format!("negative {}", to_words_ordinal(-num, options));
+    if options.append_only {
+        w.write_str(" only")?;
     }
-    let words = to_words(num as f64, options);
+    w.finish()
+}
 
-    // Check for simple cases
-    for &(card, ord) in ORDINAL_ONES.iter() {
-        if words == card {
-            return format_with_options(ord, options);
-        }
+/// True if `ordinal` is the ordinal form of the cardinal word `cardinal`
+/// ("twelfth" of "twelve").
+pub(crate) fn is_ordinal_of(ordinal: &str, cardinal: &str) -> bool {
+    let mut rest = ordinal;
+    write_ordinal_word(&mut StripPrefix(&mut rest), cardinal).is_ok() && rest.is_empty()
+}
+
+/// Writes the ordinal form of a single cardinal word ("twelve" -> "twelfth",
+/// "twenty" -> "twentieth", "hundred" -> "hundredth").
+fn write_ordinal_word<W: fmt::Write>(w: &mut W, cardinal: &str) -> fmt::Result {
+    if let Some(&(_, ord)) = ORDINAL_ONES.iter().find(|(card, _)| *card == cardinal) {
+        return w.write_str(ord);
     }
-    for &(card, ord) in ORDINAL_TENS.iter() {
-        if words == card {
-            return format_with_options(ord, options);
-        }
+    if cardinal == "twelve" {
+        return w.write_str("twelfth");
     }
-    if words == "ninety" {
-        return format_with_options("ninetieth", options);
+    match cardinal.strip_suffix('y') {
+        Some(stem) => write!(w, "{}ieth", stem),
+        None => write!(w, "{}th", cardinal),
     }
+}
 
-    // Handle hyphenated (e.g., twenty-one)
-    if let Some(idx) = words.find('-') {
-        let (prefix, last) = words.split_at(idx + 1);
-        let last = &last[0..];
-        for &(card, ord) in ORDINAL_ONES.iter() {
-            if last == card {
-                return format_with_options(&format!("{}{}", prefix, ord), options);
-            }
-        }
+/// Matches written text against the start of a string, failing on the
+/// first difference.
+struct StripPrefix<'a, 'b>(&'a mut &'b str);
+
+impl fmt::Write for StripPrefix<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        *self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
     }
+}
 
-    // Handle last word (e.g., one thousand two hundred thirty-four)
-    if let Some(idx) = words.rfind(' ') {
-        let (prefix, last) = words.split_at(idx + 1);
-        let last = &last[0..];
-        for &(card, ord) in ORDINAL_ONES.iter() {
-            if last == card {
-                return format_with_options(&format!("{}{}", prefix, ord), options);
-            }
-        }
-        for &(card, ord) in ORDINAL_TENS.iter() {
-            if last == card {
-                return format_with_options(&format!("{}{}", prefix, ord), options);
-            }
-        }
-        if last == "ninety" {
-            return format_with_options(&format!("{}ninetieth", prefix), options);
+/// Passes text through, holding back the last word so `finish` can write it
+/// as an ordinal. Number words are at most 11 letters ("quadrillion").
+struct LastWord<W> {
+    inner: W,
+    word: [u8; 16],
+    len: usize,
+}
+
+impl<W: fmt::Write> LastWord<W> {
+    fn new(inner: W) -> Self {
+        LastWord {
+            inner,
+            word: [0; 16],
+            len: 0,
         }
     }
 
-    // Default: add "th"
-    format_with_options(&format!("{}th", words), options)
+    fn finish(&mut self) -> fmt::Result {
+        let word = self.word;
+        let word = core::str::from_utf8(&word[..self.len]).unwrap_or_default();
+        write_ordinal_word(&mut self.inner, word)
+    }
 }
 
-fn format_with_options(s: &str, options: &Options) -> String {
-    let mut result = s.to_string();
-    if options.append_only {
-        result.push_str(" only");
-    }
-    if options.capitalize && !result.is_empty() {
-        let mut chars = result.chars();
-        if let Some(first) = chars.next() {
-            result = first.to_uppercase().collect::<String>() + chars.as_str();
+impl<W: fmt::Write> fmt::Write for LastWord<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if c == ' ' || c == '-' {
+                let word = core::str::from_utf8(&self.word[..self.len]).unwrap_or_default();
+                self.inner.write_str(word)?;
+                self.inner.write_char(c)?;
+                self.len = 0;
+            } else {
+                let mut bytes = [0; 4];
+                let bytes = c.encode_utf8(&mut bytes).as_bytes();
+                self.word
+                    .get_mut(self.len..self.len + bytes.len())
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(bytes);
+                self.len += bytes.len();
+            }
         }
+        Ok(())
     }
-    if options.uppercase {
-        result = result.to_uppercase();
-    }
-    result
 }
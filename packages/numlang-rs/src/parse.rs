@@ -1,192 +1,513 @@
+use crate::cardinal::{ONES, SCALES, TENS};
+use crate::number::Number;
+use crate::ordinal::is_ordinal_of;
 use crate::string::from_string;
-use std::collections::HashMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
 
-pub fn from_words(s: &str) -> Result<f64, String> {
+/// Fraction words and their denominators ("three quarters" -> 3/4).
+/// "hundredth" and "thousandth" are left out: "one hundredth" is how
+/// `to_words_ordinal` writes 100th.
+pub(crate) const FRACTION_WORDS: &[(&str, i128)] = &[
+    ("half", 2),
+    ("halves", 2),
+    ("third", 3),
+    ("thirds", 3),
+    ("quarter", 4),
+    ("quarters", 4),
+    ("fourth", 4),
+    ("fourths", 4),
+    ("fifth", 5),
+    ("fifths", 5),
+    ("sixth", 6),
+    ("sixths", 6),
+    ("seventh", 7),
+    ("sevenths", 7),
+    ("eighth", 8),
+    ("eighths", 8),
+    ("ninth", 9),
+    ("ninths", 9),
+    ("tenth", 10),
+    ("tenths", 10),
+    ("eleventh", 11),
+    ("elevenths", 11),
+    ("twelfth", 12),
+    ("twelfths", 12),
+    ("thirteenth", 13),
+    ("thirteenths", 13),
+    ("fourteenth", 14),
+    ("fourteenths", 14),
+    ("fifteenth", 15),
+    ("fifteenths", 15),
+    ("sixteenth", 16),
+    ("sixteenths", 16),
+    ("seventeenth", 17),
+    ("seventeenths", 17),
+    ("eighteenth", 18),
+    ("eighteenths", 18),
+    ("nineteenth", 19),
+    ("nineteenths", 19),
+    ("twentieth", 20),
+    ("twentieths", 20),
+    ("thirtieth", 30),
+    ("thirtieths", 30),
+    ("fortieth", 40),
+    ("fortieths", 40),
+    ("fiftieth", 50),
+    ("fiftieths", 50),
+    ("sixtieth", 60),
+    ("sixtieths", 60),
+    ("seventieth", 70),
+    ("seventieths", 70),
+    ("eightieth", 80),
+    ("eightieths", 80),
+    ("ninetieth", 90),
+    ("ninetieths", 90),
+];
+
+/// Why `from_words` couldn't read its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte position in the input of the word the error is about, or the
+    /// input's length if it ended too soon.
+    pub position: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A word that isn't part of a number ("banana").
+    UnknownWord(String),
+    /// A word after "point" that isn't a digit ("point twelve").
+    InvalidDecimal(String),
+    /// The number is too large to represent.
+    Overflow,
+    /// Strict mode only: number words that don't form a well-formed number,
+    /// with a description ("'one' can't come here").
+    Malformed(String),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnknownWord(word) => write!(f, "Unknown token: {}", word),
+            ParseErrorKind::InvalidDecimal(word) => write!(f, "Invalid decimal digit: {}", word),
+            ParseErrorKind::Overflow => f.write_str("Overflow: number is too large"),
+            ParseErrorKind::Malformed(message) => f.write_str(message),
+        }
+    }
+}
+
+/// "position 4: 'one' can't come here"
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "position {}: {}", self.position, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// How strictly `from_words_with` checks the words it is given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Accept any sequence of number words, adding up parts whose order
+    /// doesn't make sense ("five twenty" -> 25). Frequency adverbs are
+    /// found anywhere in the input ("twice daily" -> 2).
+    #[default]
+    Lenient,
+    /// Reject phrases that aren't well-formed numbers: scales out of order
+    /// ("hundred thousand hundred"), repeated or misplaced parts ("one one",
+    /// "five twenty"), words after the number and input with no number in
+    /// it ("", "negative"), with a [`ParseErrorKind::Malformed`] error.
+    Strict,
+}
+
+/// Parses number words (or a number string) in lenient mode.
+pub fn from_words(s: &str) -> Result<f64, ParseError> {
+    from_words_with(s, ParseMode::Lenient)
+}
+
+/// Parses number words (or a number string) with the given mode.
+pub fn from_words_with(s: &str, mode: ParseMode) -> Result<f64, ParseError> {
     // Try to parse as a number string first
-    if let Ok(num) = from_string(s) {
+    if let Ok(num) = from_string(without_only(s)) {
         return Ok(num);
     }
+    parse_words(s, mode).map(Number::to_f64)
+}
+
+/// Like `from_words`, but returns the exact value: "one third" is 1/3 and
+/// "twelve point five zero" is 12.50, rather than the nearest `f64`.
+pub fn parse_exact(s: &str) -> Result<Number, ParseError> {
+    parse_exact_with(s, ParseMode::Lenient)
+}
 
-    let freq_adverbs = [("once", 1.0), ("twice", 2.0), ("thrice", 3.0)];
+/// Like `from_words_with`, but returns the exact value.
+pub fn parse_exact_with(s: &str, mode: ParseMode) -> Result<Number, ParseError> {
+    // Number strings other than plain decimals ("1e5") aren't read exactly,
+    // and fail as unknown words.
+    match decimal_string(without_only(s).trim()) {
+        Some(number) => Ok(number),
+        None => parse_words(s, mode),
+    }
+}
 
-    let s_lower = s.to_lowercase();
-    for &(w, v) in freq_adverbs.iter() {
-        if s_lower.contains(w) {
-            return Ok(v);
-        }
+/// Drops a trailing "only", which `to_words` adds with `append_only`.
+fn without_only(s: &str) -> &str {
+    let trimmed = s.trim_end();
+    match trimmed.rsplit_once(char::is_whitespace) {
+        Some((rest, last)) if last.eq_ignore_ascii_case("only") => rest,
+        _ => s,
     }
+}
 
-    let ones = [
-        ("zero", 0),
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9),
-        ("ten", 10),
-        ("eleven", 11),
-        ("twelve", 12),
-        ("thirteen", 13),
-        ("fourteen", 14),
-        ("fifteen", 15),
-        ("sixteen", 16),
-        ("seventeen", 17),
-        ("eighteen", 18),
-        ("nineteen", 19),
-    ];
-    let tens = [
-        ("twenty", 20),
-        ("thirty", 30),
-        ("forty", 40),
-        ("fifty", 50),
-        ("sixty", 60),
-        ("seventy", 70),
-        ("eighty", 80),
-        ("ninety", 90),
-    ];
-    let scales = [
-        ("hundred", 100),
-        ("thousand", 1_000),
-        ("million", 1_000_000),
-        ("billion", 1_000_000_000),
-        ("trillion", 1_000_000_000_000),
-    ];
-    let fractions = [
-        ("half", 2.0),
-        ("halves", 2.0),
-        ("third", 3.0),
-        ("thirds", 3.0),
-        ("quarter", 4.0),
-        ("quarters", 4.0),
-        ("fourth", 4.0),
-        ("fourths", 4.0),
-        ("fifth", 5.0),
-        ("fifths", 5.0),
-        ("sixth", 6.0),
-        ("sixths", 6.0),
-        ("seventh", 7.0),
-        ("sevenths", 7.0),
-        ("eighth", 8.0),
-        ("eighths", 8.0),
-        ("ninth", 9.0),
-        ("ninths", 9.0),
-        ("tenth", 10.0),
-        ("tenths", 10.0),
-    ];
-
-    let mut word_map = HashMap::new();
-    for &(w, v) in ones.iter().chain(tens.iter()).chain(scales.iter()) {
-        word_map.insert(w, v);
+/// Parses a plain decimal such as "-12.50" exactly.
+fn decimal_string(s: &str) -> Option<Number> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if !(whole.chars().chain(fraction.chars())).all(|c| c.is_ascii_digit()) {
+        return None;
     }
+    let digits: i128 = format!("{}{}", whole, fraction).parse().ok()?;
+    let number = if fraction.is_empty() {
+        Number::Integer(digits)
+    } else {
+        Number::Decimal {
+            digits,
+            scale: fraction.len() as u32,
+        }
+    };
+    Some(if negative { number.negate() } else { number })
+}
+
+fn parse_words(s: &str, mode: ParseMode) -> Result<Number, ParseError> {
+    let strict = mode == ParseMode::Strict;
 
-    let mut fraction_map = HashMap::new();
-    for &(w, denom) in fractions.iter() {
-        fraction_map.insert(w, denom);
+    let freq_adverbs = [("once", 1), ("twice", 2), ("thrice", 3)];
+
+    let s_lower = s.to_lowercase();
+    for &(w, v) in freq_adverbs.iter() {
+        let found = if strict {
+            s_lower.trim() == w
+        } else {
+            s_lower.contains(w)
+        };
+        if found {
+            return Ok(Number::Integer(v));
+        }
     }
 
-    let s_clean = s_lower.replace("-", " ");
-    let tokens: Vec<&str> = s_clean.split_whitespace().collect();
+    let words = split_words(s);
+    let tokens: Vec<&str> = words.iter().map(|(_, w)| w.as_str()).collect();
+    // The denominator if the word at `i` is a fraction. "third" is a
+    // fraction after "one" or "a", otherwise an ordinal ("twenty-third").
+    let fraction_at = |i: usize| -> Option<i128> {
+        let word = *tokens.get(i)?;
+        let denom = fraction_denominator(word)?;
+        let ordinal = ordinal_value(word).is_some();
+        (!ordinal || (i > 0 && matches!(tokens[i - 1], "one" | "a"))).then_some(denom)
+    };
+    // An error about the word at `i`.
+    let at = |i: usize, kind: ParseErrorKind| ParseError {
+        position: words.get(i).map_or(s.len(), |(pos, _)| *pos),
+        kind,
+    };
+    let malformed = |i: usize, message: String| at(i, ParseErrorKind::Malformed(message));
+    let mut grammar = Grammar::default();
 
-    let mut result = 0i64;
-    let mut current = 0i64;
+    // Wide enough for every scale in `SCALES`; checked so larger input
+    // errors rather than overflowing.
+    let mut result = 0i128;
+    let mut current = 0i128;
+    let overflow = |i: usize| at(i, ParseErrorKind::Overflow);
+    // The last scale of a thousand or more added into `result`.
+    let mut last_scale = 0i128;
     let mut negative = false;
     let mut i = 0;
-    let len = tokens.len();
+    // `to_words` can end with "only" (`Options::append_only`).
+    let len = match tokens.last() {
+        Some(&"only") if tokens.len() > 1 => tokens.len() - 1,
+        _ => tokens.len(),
+    };
 
+    // True once a number word has been read (strict mode only).
+    let mut seen_number = false;
     let mut decimal_str = String::new();
-    let mut fraction_value = 0.0;
-    let mut found_fraction = false;
+    // (numerator, denominator) of a trailing fraction.
+    let mut fraction: Option<(i128, i128)> = None;
+    // Index just past the words making up the number.
+    let mut end = len;
 
     while i < len {
         let token = tokens[i];
         if token == "negative" {
+            if strict && i > 0 {
+                return Err(malformed(i, "'negative' must come first".to_string()));
+            }
             negative = true;
         } else if token == "point" {
             i += 1;
+            if strict && i == len {
+                return Err(malformed(i, "expected a digit after 'point'".to_string()));
+            }
             while i < len {
-                if let Some(&v) = word_map.get(tokens[i]) {
-                    if v >= 0 && v <= 9 {
+                if let Some(v) = number_value(tokens[i]) {
+                    if (0..=9).contains(&v) {
                         decimal_str.push_str(&v.to_string());
                     } else {
-                        return Err(format!("Invalid decimal digit: {}", tokens[i]));
+                        return Err(at(i, ParseErrorKind::InvalidDecimal(tokens[i].to_string())));
                     }
                 } else {
-                    return Err(format!("Unknown token: {}", tokens[i]));
+                    return Err(at(i, ParseErrorKind::UnknownWord(tokens[i].to_string())));
                 }
                 i += 1;
             }
             break;
         } else if token == "and" {
-            i += 1;
-            let mut numerator = 1;
-            if i < len && tokens[i] == "a" {
-                numerator = 1;
-                i += 1;
-            } else if i < len {
-                if let Some(&v) = word_map.get(tokens[i]) {
-                    numerator = v;
-                    i += 1;
-                }
+            // "and a half", "and three quarters"; otherwise "and" only joins
+            // parts of the number ("two hundred and five").
+            let numerator = match tokens.get(i + 1) {
+                Some(&"a") => Some(1),
+                Some(t) => number_value(t),
+                None => None,
+            };
+            let denom = fraction_at(i + 2);
+            if let Some((numerator, denom)) = numerator.zip(denom) {
+                fraction = Some((numerator, denom));
+                end = i + 3;
+                break;
             }
-            if i < len {
-                let frac_token = tokens[i];
-                if let Some(&denom) = fraction_map.get(frac_token) {
-                    fraction_value = numerator as f64 / denom;
-                    found_fraction = true;
-                } else {
-                    return Err(format!("Unknown fraction: {}", frac_token));
-                }
+            if strict {
+                grammar.and(i + 1 < len).map_err(|e| malformed(i, e))?;
             }
-            break;
-        } else if let Some(&denom) = fraction_map.get(token) {
+        } else if let Some(denom) = fraction_at(i) {
             let numerator = if current > 0 { current } else { 1 };
-            fraction_value = numerator as f64 / denom;
-            found_fraction = true;
+            fraction = Some((numerator, denom));
             current = 0;
+            end = i + 1;
             break;
-        } else if let Some(&v) = word_map.get(token) {
+        } else if let Some((v, ordinal)) = number_value(token)
+            .map(|v| (v, false))
+            .or_else(|| ordinal_value(token).map(|v| (v, true)))
+        {
+            if strict {
+                grammar.number(token, v).map_err(|e| malformed(i, e))?;
+                seen_number = true;
+            }
             if v < 100 {
-                current += v;
+                current = current.checked_add(v).ok_or_else(|| overflow(i))?;
+            } else if v >= 1000 && v >= last_scale && last_scale > 0 {
+                // A scale no smaller than an earlier one multiplies
+                // everything before it ("nine hundred trillion trillion").
+                result = result
+                    .checked_add(current)
+                    .and_then(|r| r.checked_mul(v))
+                    .ok_or_else(|| overflow(i))?;
+                current = 0;
+                last_scale = v;
             } else {
                 if current == 0 {
                     current = 1;
                 }
-                current *= v;
+                current = current.checked_mul(v).ok_or_else(|| overflow(i))?;
                 if v >= 1000 {
-                    result += current;
+                    result = result.checked_add(current).ok_or_else(|| overflow(i))?;
                     current = 0;
+                    last_scale = v;
                 }
             }
+            // An ordinal ends the number ("twenty-first").
+            if ordinal {
+                end = i + 1;
+                break;
+            }
         } else if token == "a" {
-            current += 1;
-        } else {
-            if let Some(&denom) = fraction_map.get(token) {
-                if current > 0 {
-                    fraction_value = current as f64 / denom;
-                    found_fraction = true;
-                    current = 0;
-                    break;
+            if strict {
+                let next_is_scale_or_fraction = tokens.get(i + 1).is_some_and(|t| {
+                    fraction_denominator(t).is_some() || number_value(t).is_some_and(|v| v >= 100)
+                });
+                if !next_is_scale_or_fraction {
+                    return Err(malformed(
+                        i,
+                        "'a' must be followed by a scale or fraction".to_string(),
+                    ));
                 }
+                grammar.number("a", 1).map_err(|e| malformed(i, e))?;
+                seen_number = true;
             }
-            return Err(format!("Unknown token: {}", token));
+            current = current.checked_add(1).ok_or_else(|| overflow(i))?;
+        } else {
+            return Err(at(i, ParseErrorKind::UnknownWord(token.to_string())));
         }
         i += 1;
     }
-    result += current;
-    let mut value = result as f64;
-    if !decimal_str.is_empty() {
-        let decimal_val: f64 = format!("0.{}", decimal_str).parse().unwrap_or(0.0);
-        value += decimal_val;
+    if strict && end < len {
+        return Err(malformed(
+            end,
+            format!("unexpected '{}' after the number", tokens[end]),
+        ));
     }
-    if found_fraction {
-        value += fraction_value;
+    if strict && !seen_number && fraction.is_none() && decimal_str.is_empty() {
+        return Err(malformed(len, "expected a number".to_string()));
     }
-    if negative {
-        value = -value;
+    result = result.checked_add(current).ok_or_else(|| overflow(len))?;
+    let value = if let Some((numerator, denominator)) = fraction {
+        let numerator = result
+            .checked_mul(denominator)
+            .and_then(|n| n.checked_add(numerator))
+            .ok_or_else(|| overflow(len))?;
+        Number::rational(numerator, denominator).ok_or_else(|| overflow(len))?
+    } else if !decimal_str.is_empty() {
+        let scale = decimal_str.len() as u32;
+        let digits = 10i128
+            .checked_pow(scale)
+            .and_then(|p| result.checked_mul(p))
+            .zip(decimal_str.parse::<i128>().ok())
+            .and_then(|(whole, fraction)| whole.checked_add(fraction))
+            .ok_or_else(|| overflow(len))?;
+        Number::Decimal { digits, scale }
+    } else {
+        Number::Integer(result)
+    };
+    Ok(if negative { value.negate() } else { value })
+}
+
+/// The cardinal number words and their values, from the tables `to_words`
+/// uses.
+fn number_words() -> impl Iterator<Item = (&'static str, i128)> {
+    let ones = ONES
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(v, &w)| (w, v as i128));
+    let tens = TENS
+        .iter()
+        .enumerate()
+        .skip(2)
+        .map(|(v, &w)| (w, v as i128 * 10));
+    let scales = SCALES
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, &w)| (w, 1000i128.pow(k as u32)));
+    [("zero", 0), ("hundred", 100)]
+        .into_iter()
+        .chain(ones)
+        .chain(tens)
+        .chain(scales)
+}
+
+fn number_value(word: &str) -> Option<i128> {
+    number_words().find(|&(w, _)| w == word).map(|(_, v)| v)
+}
+
+/// Value of an ordinal word `to_words_ordinal` ends with ("first",
+/// "twelfth", "hundredth").
+fn ordinal_value(word: &str) -> Option<i128> {
+    number_words()
+        .find(|&(w, _)| is_ordinal_of(word, w))
+        .map(|(_, v)| v)
+}
+
+fn fraction_denominator(word: &str) -> Option<i128> {
+    FRACTION_WORDS
+        .iter()
+        .find(|&&(w, _)| w == word)
+        .map(|&(_, denom)| denom)
+}
+
+/// Lowercased words with their byte offsets in `s`. Hyphens and commas
+/// separate words like spaces do ("twenty-one", "one million, two").
+fn split_words(s: &str) -> Vec<(usize, String)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() || c == '-' || c == ',' {
+            if let Some(start) = start.take() {
+                words.push((start, s[start..i].to_lowercase()));
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(start) = start {
+        words.push((start, s[start..].to_lowercase()));
+    }
+    words
+}
+
+/// What strict mode has seen so far, to check each word may come next.
+#[derive(Default)]
+struct Grammar {
+    /// The last scale of a thousand or more; later scales must be smaller.
+    scale: Option<i128>,
+    /// Parts of the current group below a thousand.
+    hundred: bool,
+    tens: bool,
+    teen: bool,
+    ones: bool,
+    zero: bool,
+    /// The previous word was "hundred" or a larger scale.
+    after_scale: bool,
+}
+
+impl Grammar {
+    fn number(&mut self, word: &str, value: i128) -> Result<(), String> {
+        let misplaced = || Err(format!("'{}' can't come here", word));
+        if self.zero {
+            return Err(format!("'{}' can't follow 'zero'", word));
+        }
+        let group_empty = !(self.hundred || self.tens || self.teen || self.ones);
+        match value {
+            0 if group_empty && self.scale.is_none() => self.zero = true,
+            1..=9 if !self.ones && !self.teen => self.ones = true,
+            10..=19 if !self.tens && !self.teen && !self.ones => self.teen = true,
+            20..=90 if !self.tens && !self.teen && !self.ones => self.tens = true,
+            // "five hundred", and "twelve hundred" or "twenty-five hundred"
+            // on their own, but not "one thousand twelve hundred".
+            100 => {
+                let hundreds = self.ones || self.teen;
+                let over_ten = self.tens || self.teen;
+                if self.hundred || !hundreds || (over_ten && self.scale.is_some()) {
+                    return misplaced();
+                }
+                self.hundred = true;
+                self.tens = false;
+                self.teen = false;
+                self.ones = false;
+            }
+            v if v >= 1000 => {
+                if self.scale.is_some_and(|s| s <= v) {
+                    return Err(format!("'{}' is out of order", word));
+                }
+                if group_empty {
+                    return Err(format!("'{}' needs a number before it", word));
+                }
+                self.scale = Some(v);
+                self.hundred = false;
+                self.tens = false;
+                self.teen = false;
+                self.ones = false;
+            }
+            _ => return misplaced(),
+        }
+        self.after_scale = value >= 100;
+        Ok(())
+    }
+
+    /// "and" may only join a scale to what follows ("two hundred and five").
+    fn and(&mut self, more_follows: bool) -> Result<(), String> {
+        if !self.after_scale || !more_follows {
+            return Err("'and' can't come here".to_string());
+        }
+        self.after_scale = false;
+        Ok(())
     }
-    Ok(value)
 }
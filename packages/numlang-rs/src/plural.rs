@@ -1,13 +1,165 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// Words that introduce a modifier after the head noun ("per kilogram",
+/// "of treatment").
+const PREPOSITIONS: [&str; 10] = [
+    "per", "of", "in", "for", "to", "with", "on", "at", "by", "from",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PluralOptions {
+    /// Also treat short vowel-less words (e.g. "bpm") as abbreviations when
+    /// they aren't in the abbreviation lexicon.
+    pub abbreviation_heuristic: bool,
+}
 
 /// Returns the plural form of a unit or word.
-/// Abbreviations (e.g., "mg", "ml") are returned unchanged.
+/// Known abbreviations (e.g., "mg", "ml", "tbsp") are returned unchanged.
+/// In multi-word phrases only the head noun is inflected:
+/// "milligram per kilogram" -> "milligrams per kilogram", "ear tag" -> "ear tags".
+/// Lowercase, Title and UPPER case are kept: "Foot" -> "Feet", "MOUSE" -> "MICE".
 pub fn to_plural(singular: &str) -> String {
-    if is_abbreviation(singular) {
+    to_plural_with(singular, &PluralOptions::default())
+}
+
+/// Like `to_plural`, with control over how abbreviations are detected.
+pub fn to_plural_with(singular: &str, options: &PluralOptions) -> String {
+    inflect_head(
+        singular,
+        &|w| is_abbreviation(w, options),
+        |word, is_abbreviation| {
+            // Heads that are already unit plurals ("milligrams") are kept.
+            if crate::unit::registry().is_unit_plural(word) {
+                word.to_string()
+            } else {
+                plural_word(word, is_abbreviation)
+            }
+        },
+    )
+}
+
+/// Returns the singular form of a unit or word.
+/// Known abbreviations (e.g., "mg", "ml", "tbsp") are returned unchanged.
+/// Handles pluralized abbreviations like "mls" -> "ml", "kgs" -> "kg".
+/// In multi-word phrases only the head noun is inflected:
+/// "milligrams per kilogram" -> "milligram per kilogram".
+pub fn to_singular(unit: &str) -> String {
+    to_singular_with(unit, &PluralOptions::default())
+}
+
+/// Like `to_singular`, with control over how abbreviations are detected.
+pub fn to_singular_with(unit: &str, options: &PluralOptions) -> String {
+    inflect_head(unit, &|w| is_abbreviation(w, options), singular_word)
+}
+
+/// Adds words that `to_plural` and `to_singular` should leave unchanged,
+/// alongside the unit abbreviations they already know (e.g. "prn", "bid").
+pub fn register_abbreviations(abbreviations: &[&str]) {
+    let mut custom = custom_abbreviations()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    custom.extend(abbreviations.iter().map(|a| a.trim().to_lowercase()));
+}
+
+/// Pluralises using only the given abbreviation check. Used while building
+/// the unit registry, which can't consult itself.
+pub(crate) fn to_plural_using(singular: &str, is_abbreviation: &dyn Fn(&str) -> bool) -> String {
+    inflect_head(singular, is_abbreviation, plural_word)
+}
+
+type Inflect = fn(&str, &dyn Fn(&str) -> bool) -> String;
+
+/// Applies `inflect` to the head noun of a phrase and keeps the rest as-is.
+/// Phrases that are themselves abbreviations ("fl oz") are left unchanged.
+///
+/// The head is the word before the first preposition ("milligram per
+/// kilogram"), the part before the first '/' ("drop/kg"), or otherwise the
+/// last word ("fluid ounce").
+fn inflect_head(phrase: &str, is_abbreviation: &dyn Fn(&str) -> bool, inflect: Inflect) -> String {
+    let trimmed = phrase.trim();
+    if trimmed.contains([' ', '/']) && is_abbreviation(trimmed) {
+        return phrase.to_string();
+    }
+    let (start, end) = head_span(phrase);
+    let head = &phrase[start..end];
+    // Irregulars are keyed in lowercase; inflect that and restore the casing.
+    let inflected = match Casing::of(head) {
+        Some(casing) => casing.apply(&inflect(&head.to_lowercase(), is_abbreviation)),
+        None => inflect(head, is_abbreviation),
+    };
+    format!("{}{}{}", &phrase[..start], inflected, &phrase[end..])
+}
+
+/// Casing patterns that are re-applied after inflection. Mixed-case words
+/// ("mEq") are inflected as given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Casing {
+    Lower,
+    Title,
+    Upper,
+}
+
+impl Casing {
+    fn of(word: &str) -> Option<Casing> {
+        let mut chars = word.chars();
+        let first_upper = chars.next()?.is_uppercase();
+        let rest_upper = chars.clone().any(char::is_uppercase);
+        let rest_lower = chars.any(char::is_lowercase);
+        match (first_upper, rest_upper, rest_lower) {
+            (false, false, _) => Some(Casing::Lower),
+            (true, false, true) => Some(Casing::Title),
+            (true, _, false) => Some(Casing::Upper),
+            _ => None,
+        }
+    }
+
+    fn apply(self, word: &str) -> String {
+        match self {
+            Casing::Lower => word.to_string(),
+            Casing::Upper => word.to_uppercase(),
+            Casing::Title => {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+        }
+    }
+}
+
+/// Byte range of the head noun within `phrase`.
+fn head_span(phrase: &str) -> (usize, usize) {
+    let mut head = (0, phrase.len());
+    let mut offset = 0;
+    for word in phrase.split(' ') {
+        let start = offset;
+        offset += word.len() + 1;
+        if word.is_empty() {
+            continue;
+        }
+        if start > 0 && PREPOSITIONS.contains(&word.to_lowercase().as_str()) {
+            break;
+        }
+        // "X/Y" reads as "X per Y".
+        if let Some(slash) = word.find('/').filter(|&i| i > 0) {
+            return (start, start + slash);
+        }
+        head = (start, start + word.len());
+    }
+    head
+}
+
+fn plural_word(singular: &str, is_abbreviation: &dyn Fn(&str) -> bool) -> String {
+    // Plurals from the table ("children") are already inflected.
+    if is_abbreviation(singular)
+        || is_invariant(singular)
+        || irregular_singulars().contains_key(singular)
+    {
         return singular.to_string();
     }
-    let irregular = irregular_plurals();
-    if let Some(&plural) = irregular.get(singular) {
+    if let Some(&plural) = irregular_plurals().get(singular) {
         return plural.to_string();
     }
     // Rules-based
@@ -32,10 +184,7 @@ pub fn to_plural(singular: &str) -> String {
     format!("{}s", singular)
 }
 
-/// Returns the singular form of a unit or word.
-/// Abbreviations (e.g., "mg", "ml") are returned unchanged.
-/// Handles pluralized abbreviations like "mls" -> "ml", "kgs" -> "kg".
-pub fn to_singular(unit: &str) -> String {
+fn singular_word(unit: &str, is_abbreviation: &dyn Fn(&str) -> bool) -> String {
     // Handle pluralized abbreviations like "mls" -> "ml", "kgs" -> "kg"
     if unit.len() > 2 && unit.ends_with('s') {
         let candidate = &unit[..unit.len() - 1];
@@ -43,25 +192,31 @@ pub fn to_singular(unit: &str) -> String {
             return candidate.to_string();
         }
     }
-    if is_abbreviation(unit) {
+    if is_abbreviation(unit) || is_invariant(unit) {
         return unit.to_string();
     }
-    let irregular = irregular_singulars();
-    if let Some(&singular) = irregular.get(unit) {
+    if let Some(&singular) = irregular_singulars().get(unit) {
         return singular.to_string();
     }
+    // Singulars from the tables ("gas", "bolus") are left alone.
+    if irregular_plurals().contains_key(unit) || US_NOUNS.contains(&unit) {
+        return unit.to_string();
+    }
     // Rules-based
     if unit.ends_with("ies") && unit.len() > 3 {
         let base = &unit[..unit.len() - 3];
         return format!("{}y", base);
     }
-    if unit.ends_with("es")
-        && (unit.ends_with("ses")
-            || unit.ends_with("xes")
-            || unit.ends_with("zes")
-            || unit.ends_with("ches")
-            || unit.ends_with("shes"))
-    {
+    if unit.ends_with("ses") || unit.ends_with("zes") {
+        // "glasses", "buzzes" and the nouns in "-us" ("boluses") add "es";
+        // "doses", "causes" and "sizes" only add "s".
+        let stem = &unit[..unit.len() - 2];
+        if stem.ends_with("ss") || stem.ends_with("zz") || US_NOUNS.contains(&stem) {
+            return stem.to_string();
+        }
+        return unit[..unit.len() - 1].to_string();
+    }
+    if unit.ends_with("xes") || unit.ends_with("ches") || unit.ends_with("shes") {
         return unit[..unit.len() - 2].to_string();
     }
     if unit.ends_with('s') && unit.len() > 1 {
@@ -70,8 +225,28 @@ pub fn to_singular(unit: &str) -> String {
     unit.to_string()
 }
 
+/// Returns true if `s` is a known unit abbreviation or a registered custom
+/// abbreviation, or, when enabled, looks like one.
+fn is_abbreviation(s: &str, options: &PluralOptions) -> bool {
+    let key = s.trim().to_lowercase();
+    if crate::unit::registry().is_abbreviation(&key)
+        || custom_abbreviations()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(&key)
+    {
+        return true;
+    }
+    options.abbreviation_heuristic && looks_like_abbreviation(s)
+}
+
+fn custom_abbreviations() -> &'static RwLock<HashSet<String>> {
+    static CUSTOM: OnceLock<RwLock<HashSet<String>>> = OnceLock::new();
+    CUSTOM.get_or_init(Default::default)
+}
+
 /// Returns true if the string is likely an abbreviation (all lowercase or uppercase, <=4 chars, contains no vowels).
-fn is_abbreviation(s: &str) -> bool {
+fn looks_like_abbreviation(s: &str) -> bool {
     let s = s.trim();
     let len = s.len();
     if len == 0 || len > 4 {
@@ -85,49 +260,162 @@ fn is_abbreviation(s: &str) -> bool {
     is_alpha && is_upper_or_lower && !has_vowels
 }
 
-/// Irregular singular → plural map.
-fn irregular_plurals() -> HashMap<&'static str, &'static str> {
-    let mut m = HashMap::new();
-    m.insert("foot", "feet");
-    m.insert("inch", "inches");
-    m.insert("mouse", "mice");
-    m.insert("tooth", "teeth");
-    m.insert("tablet", "tablets");
-    m.insert("capsule", "capsules");
-    m.insert("patch", "patches");
-    m.insert("suppository", "suppositories");
-    m.insert("dose", "doses");
-    m.insert("chew", "chews");
-    m.insert("vial", "vials");
-    m.insert("ampule", "ampules");
-    m.insert("drop", "drops");
-    m.insert("spray", "sprays");
-    m.insert("puff", "puffs");
-    m.insert("bolus", "boluses");
-    m.insert("ampoule", "ampoules");
-    m.insert("syringe", "syringes");
-    m.insert("actuation", "actuations");
-    m.insert("scoop", "scoops");
-    m.insert("teat", "teats");
-    m.insert("collar", "collars");
-    m.insert("measure", "measures");
-    m.insert("course", "courses");
-    m.insert("device", "devices");
-    m.insert("strip", "strips");
-    m.insert("bottle", "bottles");
-    m.insert("inhaler", "inhalers");
-    m.insert("tube", "tubes");
-    m.insert("ear", "ears");
-    m.insert("eye", "eyes");
-    m.insert("cup", "cups");
-    m
+/// Plurals the rules get wrong, or that the singular rules can't undo.
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    // Vowel changes and old plurals
+    ("foot", "feet"),
+    ("tooth", "teeth"),
+    ("goose", "geese"),
+    ("mouse", "mice"),
+    ("louse", "lice"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("child", "children"),
+    ("ox", "oxen"),
+    ("person", "people"),
+    ("die", "dice"),
+    // -f/-fe -> -ves
+    ("calf", "calves"),
+    ("half", "halves"),
+    ("hoof", "hooves"),
+    ("knife", "knives"),
+    ("leaf", "leaves"),
+    ("life", "lives"),
+    ("loaf", "loaves"),
+    ("self", "selves"),
+    ("shelf", "shelves"),
+    ("thief", "thieves"),
+    ("wife", "wives"),
+    ("wolf", "wolves"),
+    // -o -> -oes
+    ("echo", "echoes"),
+    ("hero", "heroes"),
+    ("potato", "potatoes"),
+    ("tomato", "tomatoes"),
+    ("veto", "vetoes"),
+    // Latin and Greek
+    ("alga", "algae"),
+    ("alveolus", "alveoli"),
+    ("analysis", "analyses"),
+    ("appendix", "appendices"),
+    ("axis", "axes"),
+    ("bacillus", "bacilli"),
+    ("bacterium", "bacteria"),
+    ("bronchus", "bronchi"),
+    ("cilium", "cilia"),
+    ("cortex", "cortices"),
+    ("crisis", "crises"),
+    ("criterion", "criteria"),
+    ("datum", "data"),
+    ("diagnosis", "diagnoses"),
+    ("embolus", "emboli"),
+    ("flagellum", "flagella"),
+    ("fungus", "fungi"),
+    ("ganglion", "ganglia"),
+    ("genus", "genera"),
+    ("hypothesis", "hypotheses"),
+    ("index", "indices"),
+    ("larva", "larvae"),
+    ("matrix", "matrices"),
+    ("nucleus", "nuclei"),
+    ("ovum", "ova"),
+    ("phenomenon", "phenomena"),
+    ("prognosis", "prognoses"),
+    ("protozoon", "protozoa"),
+    ("pupa", "pupae"),
+    ("radius", "radii"),
+    ("stimulus", "stimuli"),
+    ("synopsis", "synopses"),
+    ("testis", "testes"),
+    ("thesis", "theses"),
+    ("thrombus", "thrombi"),
+    ("vertebra", "vertebrae"),
+    ("vertex", "vertices"),
+    // Regular plurals the singular rules would undo wrongly
+    ("gas", "gases"),
+    ("quiz", "quizzes"),
+];
+
+/// Nouns ending in "-us" whose plural adds "es" ("boluses"). Other plurals
+/// ending in "-uses" are words ending in "-use" ("causes", "excuses").
+const US_NOUNS: &[&str] = &[
+    "abacus",
+    "apparatus",
+    "bolus",
+    "bonus",
+    "bus",
+    "callus",
+    "campus",
+    "census",
+    "chorus",
+    "circus",
+    "fetus",
+    "foetus",
+    "hiatus",
+    "nexus",
+    "octopus",
+    "plexus",
+    "prospectus",
+    "sinus",
+    "status",
+    "virus",
+    "walrus",
+];
+
+/// Unit abbreviations that are also English words, so are inflected as
+/// words ("2 caps", "5 mins").
+const ABBREVIATED_WORDS: &[&str] = &["amp", "app", "cap", "dose", "gal", "in", "min", "sec"];
+
+/// True if `word` (already lowercased) is an English word even though it is
+/// also spelled as a unit abbreviation.
+pub(crate) fn is_abbreviated_word(word: &str) -> bool {
+    ABBREVIATED_WORDS.contains(&word)
+}
+
+/// Nouns whose plural is the same as the singular.
+const UNCHANGED_PLURALS: &[&str] = &[
+    "aircraft", "bison", "cod", "deer", "fish", "moose", "salmon", "series", "sheep", "species",
+    "swine", "trout",
+];
+
+/// Mass nouns, which have no plural.
+const UNCOUNTABLE: &[&str] = &[
+    "advice",
+    "bedding",
+    "blood",
+    "cattle",
+    "equipment",
+    "feed",
+    "hay",
+    "information",
+    "livestock",
+    "medication",
+    "milk",
+    "ointment",
+    "poultry",
+    "straw",
+    "water",
+    "wool",
+];
+
+/// Irregular singular → plural map, built once on first use.
+fn irregular_plurals() -> &'static HashMap<&'static str, &'static str> {
+    static MAP: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    MAP.get_or_init(|| IRREGULAR_PLURALS.iter().copied().collect())
+}
+
+/// Words returned unchanged by both `to_plural` and `to_singular`.
+fn is_invariant(word: &str) -> bool {
+    UNCHANGED_PLURALS.contains(&word) || UNCOUNTABLE.contains(&word)
 }
 
 /// Irregular plural → singular map, generated from irregular_plurals().
-fn irregular_singulars() -> HashMap<&'static str, &'static str> {
-    let mut m = HashMap::new();
-    for (sing, plur) in irregular_plurals() {
-        m.insert(plur, sing);
-    }
-    m
+fn irregular_singulars() -> &'static HashMap<&'static str, &'static str> {
+    static MAP: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    MAP.get_or_init(|| {
+        irregular_plurals()
+            .iter()
+            .map(|(&sing, &plur)| (plur, sing))
+            .collect()
+    })
 }
@@ -0,0 +1,177 @@
+use crate::cardinal::{to_words, ONES};
+use crate::plural::to_plural;
+use crate::unit::unit_def;
+use crate::{Case, Options};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QuantityOptions {
+    /// Replace unit abbreviations with their full names ("ml" -> "milliliters").
+    pub expand_unit: bool,
+    /// Spell the number out with `to_words` ("2" -> "two").
+    pub spell_out: bool,
+    /// Render common fractions as glyphs ("1½") or, when spelling out,
+    /// as words ("one and a half").
+    pub fractions: bool,
+    /// Fixed number of decimal places for numeric output (e.g. Some(1) -> "1.0").
+    pub decimals: Option<usize>,
+    /// Formatting options passed to `to_words` when spelling out. Its `case`
+    /// also applies to unit names, though never to abbreviations ("ml").
+    pub words: Options,
+}
+
+/// (numerator, denominator, glyph) for the fractions rendered specially.
+pub(crate) const FRACTIONS: [(u8, u8, &str); 9] = [
+    (1, 2, "½"),
+    (1, 3, "⅓"),
+    (2, 3, "⅔"),
+    (1, 4, "¼"),
+    (3, 4, "¾"),
+    (1, 8, "⅛"),
+    (3, 8, "⅜"),
+    (5, 8, "⅝"),
+    (7, 8, "⅞"),
+];
+
+/// Formats a value with its unit, choosing singular or plural.
+///
+/// Quantities greater than zero and no more than one take the singular
+/// ("1 tablet", "0.5 tablet", "one half tablet"); everything else takes the
+/// plural ("0 tablets", "2 tablets"). Abbreviations are left uninflected
+/// ("2 ml") unless `expand_unit` is set. NaN and the infinities are written
+/// in words, as `to_words` writes them ("infinity tablets").
+pub fn format_quantity(value: impl Into<f64>, unit: &str, options: &QuantityOptions) -> String {
+    let mut value = value.into();
+    if let Some(places) = options.decimals {
+        // Round first so the plural matches what is displayed.
+        let scale = 10f64.powi(places as i32);
+        value = (value * scale).round() / scale;
+    }
+    let singular = takes_singular(value);
+    let number = format_number(value, options);
+    let unit = unit.trim();
+    let keeps_abbreviation = !options.expand_unit && is_abbreviation(unit);
+    let unit = inflect_unit(unit, singular, options.expand_unit);
+    let unit = if keeps_abbreviation {
+        unit
+    } else {
+        unit_case(unit, options.words.case)
+    };
+    if unit.is_empty() {
+        number
+    } else {
+        format!("{} {}", number, unit)
+    }
+}
+
+/// Applies `case` to a unit name written after a number.
+pub(crate) fn unit_case(unit: String, case: Case) -> String {
+    match case {
+        // A unit follows the number, so it never starts a sentence.
+        Case::SentenceCase | Case::Preserve => unit,
+        case => case.apply(&unit),
+    }
+}
+
+/// True if a unit after `value` takes the singular: 0 < |value| <= 1.
+pub(crate) fn takes_singular(value: f64) -> bool {
+    value.abs() > 0.0 && value.abs() <= 1.0
+}
+
+/// Inflects a unit for a quantity. Abbreviations are kept unless `expand`
+/// is set; known full names are normalised to the unit's name or plural.
+pub(crate) fn inflect_unit(unit: &str, singular: bool, expand: bool) -> String {
+    let Some(def) = unit_def(unit) else {
+        // Unknown units are assumed to be given in the singular.
+        return if singular || unit.is_empty() {
+            unit.to_string()
+        } else {
+            to_plural(unit)
+        };
+    };
+    if is_abbreviation(unit) && !expand {
+        unit.to_string()
+    } else if singular {
+        def.name.clone()
+    } else {
+        def.plural.clone().unwrap_or_else(|| to_plural(&def.name))
+    }
+}
+
+/// True if `unit` is a known unit's abbreviation rather than its name.
+fn is_abbreviation(unit: &str) -> bool {
+    let Some(def) = unit_def(unit) else {
+        return false;
+    };
+    let lc = unit.to_lowercase();
+    lc != def.name && def.spellings().any(|s| s.to_lowercase() == lc)
+}
+
+fn format_number(value: f64, options: &QuantityOptions) -> String {
+    if !value.is_finite() {
+        return to_words(value, &options.words);
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    let abs = value.abs();
+    if options.fractions {
+        if let Some((whole, (num, den, glyph))) = split_fraction(abs) {
+            if options.spell_out {
+                let words = fraction_words(whole, num, den, &options.words);
+                let words = if value < 0.0 {
+                    format!("negative {}", words)
+                } else {
+                    words
+                };
+                return options.words.case.apply(&words);
+            }
+            return if whole == 0 {
+                format!("{}{}", sign, glyph)
+            } else {
+                format!("{}{}{}", sign, whole, glyph)
+            };
+        }
+    }
+    if options.spell_out {
+        return to_words(value, &options.words);
+    }
+    match options.decimals {
+        Some(places) => format!("{:.*}", places, value),
+        None => format!("{}", value),
+    }
+}
+
+/// Splits a non-negative value into a whole part and one of `FRACTIONS`.
+fn split_fraction(value: f64) -> Option<(u64, (u8, u8, &'static str))> {
+    let whole = value.trunc();
+    let frac = value - whole;
+    FRACTIONS
+        .iter()
+        .find(|(num, den, _)| (frac - *num as f64 / *den as f64).abs() < 1e-9)
+        .map(|&f| (whole as u64, f))
+}
+
+/// E.g. "one half", "three quarters", "two and a half". Case options are
+/// left to the caller.
+fn fraction_words(whole: u64, num: u8, den: u8, options: &Options) -> String {
+    let (singular, plural) = match den {
+        2 => ("half", "halves"),
+        3 => ("third", "thirds"),
+        4 => ("quarter", "quarters"),
+        _ => ("eighth", "eighths"),
+    };
+    let denom = if num == 1 { singular } else { plural };
+    if whole == 0 {
+        return format!("{} {}", ONES[num as usize], denom);
+    }
+    let plain = Options {
+        append_only: false,
+        case: Case::Preserve,
+        ..options.clone()
+    };
+    let numerator = if num == 1 { "a" } else { ONES[num as usize] };
+    format!(
+        "{} and {} {}",
+        to_words(whole as f64, &plain),
+        numerator,
+        denom
+    )
+}
@@ -0,0 +1,178 @@
+use crate::unit::{UnitDef, UnitType};
+
+/// An SI prefix: its accepted spellings (preferred first) and its name.
+struct SiPrefix {
+    spellings: &'static [&'static str],
+    name: &'static str,
+    factor: f64,
+}
+
+/// A base unit that SI prefixes can be applied to.
+struct SiBase {
+    abbreviation: &'static str,
+    symbols: &'static [&'static str],
+    name: &'static str,
+    unit_type: UnitType,
+    /// Names of the prefixes used with this base, or empty for all of them.
+    prefixes: &'static [&'static str],
+}
+
+/// Generated spellings left out because in text they are usually something
+/// else: "um" is a filler word far more often than a micrometer.
+const EXCLUDED: [&str; 1] = ["um"];
+
+/// Preferred abbreviations that differ from the generated one (which uses
+/// "mc" for micro), as established before units were generated.
+const PREFERRED: [(&str, &str); 1] = [("microliter", "ul")];
+
+/// Micro has several spellings: "mc" (preferred on labels), Greek mu
+/// (U+03BC), the micro sign (U+00B5) and ASCII "u".
+const PREFIXES: [SiPrefix; 8] = [
+    SiPrefix {
+        spellings: &["n"],
+        name: "nano",
+        factor: 1e-9,
+    },
+    SiPrefix {
+        spellings: &["mc", "μ", "µ", "u"],
+        name: "micro",
+        factor: 1e-6,
+    },
+    SiPrefix {
+        spellings: &["m"],
+        name: "milli",
+        factor: 1e-3,
+    },
+    SiPrefix {
+        spellings: &["c"],
+        name: "centi",
+        factor: 1e-2,
+    },
+    SiPrefix {
+        spellings: &["d"],
+        name: "deci",
+        factor: 1e-1,
+    },
+    SiPrefix {
+        spellings: &["k"],
+        name: "kilo",
+        factor: 1e3,
+    },
+    SiPrefix {
+        spellings: &["M"],
+        name: "mega",
+        factor: 1e6,
+    },
+    SiPrefix {
+        spellings: &["G"],
+        name: "giga",
+        factor: 1e9,
+    },
+];
+
+const BASES: [SiBase; 6] = [
+    SiBase {
+        abbreviation: "g",
+        symbols: &["g"],
+        name: "gram",
+        unit_type: UnitType::Mass,
+        prefixes: &[],
+    },
+    SiBase {
+        abbreviation: "l",
+        symbols: &["L", "l"],
+        name: "liter",
+        unit_type: UnitType::Volume,
+        prefixes: &[],
+    },
+    SiBase {
+        abbreviation: "m",
+        symbols: &["m"],
+        name: "meter",
+        unit_type: UnitType::Length,
+        prefixes: &[],
+    },
+    SiBase {
+        abbreviation: "mol",
+        symbols: &["mol"],
+        name: "mole",
+        unit_type: UnitType::Other,
+        prefixes: &[],
+    },
+    SiBase {
+        abbreviation: "iu",
+        symbols: &["IU"],
+        name: "international unit",
+        unit_type: UnitType::Other,
+        prefixes: &[],
+    },
+    SiBase {
+        abbreviation: "hz",
+        symbols: &["Hz"],
+        name: "hertz",
+        unit_type: UnitType::Other,
+        // "mhz" means MHz, not millihertz.
+        prefixes: &["kilo", "mega", "giga"],
+    },
+];
+
+/// Builds a definition for every base unit, alone and with every prefix.
+pub(crate) fn prefixed_units() -> Vec<UnitDef> {
+    let mut units = Vec::new();
+    for base in &BASES {
+        units.push(
+            UnitDef::new(base.abbreviation, &[], base.name, base.unit_type)
+                .symbols(base.symbols)
+                .factor(1.0),
+        );
+        for prefix in &PREFIXES {
+            if base.prefixes.is_empty() || base.prefixes.contains(&prefix.name) {
+                units.push(prefixed(prefix, base));
+            }
+        }
+    }
+    units
+}
+
+fn prefixed(prefix: &SiPrefix, base: &SiBase) -> UnitDef {
+    // Uppercase prefixes (mega, giga) keep their case so "Mg" is never
+    // abbreviated as milligram's "mg".
+    let generated = |spellings: Vec<String>| -> Vec<String> {
+        spellings
+            .into_iter()
+            .filter(|s| !EXCLUDED.contains(&s.as_str()))
+            .collect()
+    };
+    let mut spellings = generated(
+        prefix
+            .spellings
+            .iter()
+            .map(|p| format!("{}{}", p, base.abbreviation))
+            .collect(),
+    );
+    let symbols = generated(
+        prefix
+            .spellings
+            .iter()
+            .flat_map(|p| base.symbols.iter().map(move |s| format!("{}{}", p, s)))
+            .collect(),
+    );
+    let separator = if base.name.contains(' ') { " " } else { "" };
+    let name = format!("{}{}{}", prefix.name, separator, base.name);
+    if let Some(&(_, preferred)) = PREFERRED.iter().find(|(unit, _)| *unit == name) {
+        if let Some(i) = spellings.iter().position(|s| s == preferred) {
+            let preferred = spellings.remove(i);
+            spellings.insert(0, preferred);
+        }
+    }
+    let mut spellings = spellings.into_iter();
+    UnitDef {
+        abbreviation: spellings.next().unwrap_or_default(),
+        aliases: spellings.collect(),
+        name,
+        unit_type: base.unit_type,
+        symbols,
+        plural: None,
+        factor: Some(prefix.factor),
+    }
+}
@@ -1,3 +1,6 @@
+use alloc::format;
+use alloc::string::String;
+
 /// Parses a string containing a numeric value (integer or float) into f64.
 /// Returns Err if the string is not a valid number.
 pub fn from_string(s: &str) -> Result<f64, String> {
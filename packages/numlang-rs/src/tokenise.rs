@@ -1,6 +1,7 @@
 use crate::cardinal::{ONES, SCALES, TENS};
-use crate::unit;
+use crate::unit::{self, UnitCase};
 use std::collections::HashSet;
+use std::sync::OnceLock;
 
 /// Represents a token extracted from the input string.
 #[derive(Debug, PartialEq, Eq)]
@@ -11,7 +12,7 @@ pub enum Token {
     Unknown(String),
 }
 
-/// Token with its start and end character positions in the input.
+/// Token with its start and end byte positions in the input.
 #[derive(Debug, PartialEq, Eq)]
 pub struct TokenSpan {
     pub token: Token,
@@ -200,42 +201,45 @@ fn try_compound_unit(
     None
 }
 
-/// Tokenises an input string into tokens with character positions.
+/// Known number words (cardinal words plus "point", "negative", "and"), built once on first use.
+fn number_words() -> &'static HashSet<&'static str> {
+    static SET: OnceLock<HashSet<&'static str>> = OnceLock::new();
+    SET.get_or_init(|| {
+        let mut set: HashSet<&'static str> = ONES
+            .iter()
+            .chain(TENS.iter())
+            .chain(SCALES.iter())
+            .copied()
+            .filter(|w| !w.is_empty())
+            .collect();
+        set.insert("point");
+        set.insert("negative");
+        set.insert("and");
+        set.insert("hundred");
+        set
+    })
+}
+
+/// Tokenises an input string into tokens with byte positions in `input`.
 /// Preserves hyphenated number words as single tokens.
 /// Splits value+unit combos (e.g., "200g" -> ["200", "g"], "20mg/kg" -> ["20", "mg/kg"]).
 /// Separates leading/trailing punctuation as separate tokens.
 /// Recognises multi-word units (e.g., "kg dose", "fl oz") via a longest-match lookahead.
 pub fn tokenise(input: &str) -> Vec<TokenSpan> {
-    let mut tokens = Vec::new();
+    tokenise_with(input, UnitCase::Insensitive)
+}
 
-    // Build known number word set
-    let mut number_words = HashSet::new();
-    for &w in ONES.iter().chain(TENS.iter()).chain(SCALES.iter()) {
-        if !w.is_empty() {
-            number_words.insert(w);
-        }
-    }
-    number_words.insert("point");
-    number_words.insert("negative");
-    number_words.insert("and");
-    number_words.insert("hundred");
-
-    // Build known unit set (abbreviations and compound units)
-    let mut unit_set = HashSet::new();
-    for (abbr, _) in unit::unit_map() {
-        unit_set.insert(abbr.to_lowercase());
-    }
+/// Like `tokenise`, matching unit spellings according to `case`. With
+/// `UnitCase::Sensitive`, "5 mL" has a unit but "5 MG" doesn't. `Lenient`
+/// accepts both case-exact symbols ("MHz") and anything `Insensitive` does;
+/// use `resolve_unit` on a unit token to see whether it was a guess.
+pub fn tokenise_with(input: &str, case: UnitCase) -> Vec<TokenSpan> {
+    let mut tokens = Vec::new();
 
-    // Multi-word units sorted by descending word count (longest match first).
-    // Secondary sort is lexicographic for determinism.
-    let mut multi_word_units: Vec<Vec<String>> = unit_set
-        .iter()
-        .filter(|k| k.contains(' '))
-        .map(|k| k.split(' ').map(|w| w.to_string()).collect::<Vec<_>>())
-        .collect();
-    multi_word_units.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    let number_words = number_words();
+    let units = unit::registry();
+    let multi_word_units = units.multi_word_units();
 
-    let input = input.trim();
     let mut idx = 0;
     let input_bytes = input.as_bytes();
     let len = input.len();
@@ -261,7 +265,7 @@ pub fn tokenise(input: &str) -> Vec<TokenSpan> {
 
         // Try compound unit match before single-token classification.
         if let Some((compound_tokens, new_idx)) =
-            try_compound_unit(input, input_bytes, len, end, &raw_parts, &multi_word_units)
+            try_compound_unit(input, input_bytes, len, end, &raw_parts, multi_word_units)
         {
             tokens.extend(compound_tokens);
             idx = new_idx;
@@ -281,7 +285,7 @@ pub fn tokenise(input: &str) -> Vec<TokenSpan> {
             // Find first non-number character
             let mut i = 0;
             for (idx_char, c) in sub_lc.char_indices() {
-                if !(c.is_digit(10) || c == '.' || c == '-') {
+                if !(c.is_ascii_digit() || c == '.' || c == '-') {
                     i = idx_char;
                     break;
                 }
@@ -290,8 +294,7 @@ pub fn tokenise(input: &str) -> Vec<TokenSpan> {
             if i > 0 && i < sub_lc.len() {
                 let num = &sub[..i];
                 let unit_candidate = &sub[i..];
-                let unit_candidate_lc = unit_candidate.to_lowercase();
-                if unit_set.contains(unit_candidate_lc.as_str()) {
+                if units.contains_with(unit_candidate, case) {
                     // Check whether unit_candidate is the first word of a multi-word unit.
                     // e.g. "12kg" split → num="12", unit="kg"; if "kg dose" exists, consume "dose" too.
                     let unit_abs_start = sub_start + num.len();
@@ -306,7 +309,7 @@ pub fn tokenise(input: &str) -> Vec<TokenSpan> {
                         len,
                         end,
                         &unit_fake_parts,
-                        &multi_word_units,
+                        multi_word_units,
                     ) {
                         let mut result: Vec<TokenSpan> = vec![TokenSpan {
                             token: Token::NumberString(num.to_string()),
@@ -334,8 +337,8 @@ pub fn tokenise(input: &str) -> Vec<TokenSpan> {
             // Pure number string
             if sub_lc
                 .chars()
-                .all(|c| c.is_digit(10) || c == '.' || c == '-')
-                && sub_lc.chars().any(|c| c.is_digit(10))
+                .all(|c| c.is_ascii_digit() || c == '.' || c == '-')
+                && sub_lc.chars().any(|c| c.is_ascii_digit())
             {
                 tokens.push(TokenSpan {
                     token: Token::NumberString(sub.to_string()),
@@ -358,7 +361,7 @@ pub fn tokenise(input: &str) -> Vec<TokenSpan> {
             }
 
             // Known unit (standalone)
-            if unit_set.contains(sub_lc.as_str()) {
+            if units.contains_with(&sub, case) {
                 tokens.push(TokenSpan {
                     token: Token::Unit(sub.to_string()),
                     start: sub_start,
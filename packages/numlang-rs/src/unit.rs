@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnitType {
@@ -11,180 +13,530 @@ pub enum UnitType {
     Other,
 }
 
+/// A known unit: its preferred abbreviation, any other accepted spellings,
+/// its full singular name and its type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitDef {
+    pub abbreviation: String,
+    pub aliases: Vec<String>,
+    pub name: String,
+    pub unit_type: UnitType,
+    /// Case-exact symbols (e.g. "mL", "MU") for units whose meaning depends on
+    /// letter case. Empty for units that are always matched case-insensitively.
+    pub symbols: Vec<String>,
+    /// Plural of `name`, when `to_plural` would get it wrong.
+    pub plural: Option<String>,
+    /// Multiplier converting one of this unit into the unit it is defined
+    /// against (e.g. 0.001 for "mg", as grams). None if not convertible.
+    pub factor: Option<f64>,
+}
+
+impl UnitDef {
+    pub fn new(abbreviation: &str, aliases: &[&str], name: &str, unit_type: UnitType) -> Self {
+        UnitDef {
+            abbreviation: abbreviation.to_string(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            name: name.to_string(),
+            unit_type,
+            symbols: Vec::new(),
+            plural: None,
+            factor: None,
+        }
+    }
+
+    pub fn symbols(mut self, symbols: &[&str]) -> Self {
+        self.symbols = symbols.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn plural(mut self, plural: &str) -> Self {
+        self.plural = Some(plural.to_string());
+        self
+    }
+
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = Some(factor);
+        self
+    }
+
+    /// The preferred abbreviation followed by its aliases.
+    pub fn spellings(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.abbreviation.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+
+    /// The preferred abbreviation as it is written in case-sensitive mode:
+    /// the matching symbol ("IU" for "iu", "mEq" for "meq") if there is one.
+    pub fn symbol(&self) -> &str {
+        let abbreviation = self.abbreviation.as_str();
+        if self.symbols.iter().any(|s| s == abbreviation) {
+            return abbreviation;
+        }
+        self.symbols
+            .iter()
+            .find(|s| s.to_lowercase() == abbreviation.to_lowercase())
+            .map_or(abbreviation, String::as_str)
+    }
+}
+
 /// Expands a unit abbreviation (e.g., "ml") to its full word equivalent ("milliliter").
 /// Returns None if the unit is not recognized.
-pub fn expand_unit(unit: &str) -> Option<&'static str> {
-    let map = unit_map();
-    map.get(&unit.to_lowercase()[..])
-        .map(|(expanded, _)| *expanded)
+pub fn expand_unit(unit: &str) -> Option<String> {
+    abbreviation_def(unit).map(|def| def.name.clone())
 }
 
-/// Returns the canonical abbreviation for a full unit name (singular or plural).
-/// E.g., "milliliters" or "milliliter" -> "ml"
-pub fn abbreviate_unit(expanded: &str) -> Option<&'static str> {
-    let map = reverse_unit_map();
-    let key = expanded.trim().to_lowercase();
-    map.get(key.as_str()).copied()
+/// Returns the preferred abbreviation for a full unit name (singular or plural).
+/// E.g., "milliliters" or "milliliter" -> "ml", "micrograms" -> "mcg".
+/// Units with SI symbols get the case-exact symbol ("international unit" ->
+/// "IU"), so the result also resolves with `UnitCase::Sensitive`.
+pub fn abbreviate_unit(expanded: &str) -> Option<String> {
+    registry()
+        .names
+        .get(lowercase(expanded.trim()).as_ref())
+        .map(|def| def.symbol().to_string())
 }
 
 /// Returns the UnitType for a unit abbreviation (e.g., "mg", "ml") or expanded name.
 /// Returns None if not recognized.
 pub fn unit_type(unit: &str) -> Option<UnitType> {
-    let map = unit_map();
-    if let Some((_, typ)) = map.get(&unit.to_lowercase()[..]) {
-        return Some(*typ);
-    }
-    let rev_map = reverse_unit_map_with_type();
-    let key = unit.trim().to_lowercase();
-    rev_map.get(key.as_str()).copied()
-}
-
-pub fn unit_map() -> HashMap<&'static str, (&'static str, UnitType)> {
-    let mut m = HashMap::new();
-    // Volume
-    m.insert("ml", ("milliliter", UnitType::Volume));
-    m.insert("cc", ("cubic centimeter", UnitType::Volume));
-    m.insert("l", ("liter", UnitType::Volume));
-    m.insert("dl", ("deciliter", UnitType::Volume));
-    m.insert("ul", ("microliter", UnitType::Volume));
-    m.insert("fl oz", ("fluid ounce", UnitType::Volume));
-    m.insert("gal", ("gallon", UnitType::Volume));
-    m.insert("qt", ("quart", UnitType::Volume));
-    m.insert("pt", ("pint", UnitType::Volume));
-    m.insert("tbsp", ("tablespoon", UnitType::Volume));
-    m.insert("tsp", ("teaspoon", UnitType::Volume));
-    m.insert("gtt", ("drop", UnitType::DosageForm));
-    m.insert("spray", ("spray", UnitType::DosageForm));
-    m.insert("puff", ("puff", UnitType::DosageForm));
-    m.insert("mg/kg", ("milligram per kilogram", UnitType::Mass));
-    m.insert("ml/kg", ("milliliter per kilogram", UnitType::Volume));
-    m.insert("mcg/kg", ("microgram per kilogram", UnitType::Mass));
-    m.insert("ug/kg", ("microgram per kilogram", UnitType::Mass));
-
-    // Mass/weight
-    m.insert("mg", ("milligram", UnitType::Mass));
-    m.insert("g", ("gram", UnitType::Mass));
-    m.insert("kg", ("kilogram", UnitType::Mass));
-    m.insert("mcg", ("microgram", UnitType::Mass));
-    m.insert("μg", ("microgram", UnitType::Mass));
-    m.insert("lb", ("pound", UnitType::Mass));
-    m.insert("oz", ("ounce", UnitType::Mass));
-    m.insert("iu", ("international unit", UnitType::Other));
-    m.insert("u", ("unit", UnitType::Other));
-    m.insert("meq", ("milliequivalent", UnitType::Other));
-
-    // Length
-    m.insert("mm", ("millimeter", UnitType::Length));
-    m.insert("cm", ("centimeter", UnitType::Length));
-    m.insert("m", ("meter", UnitType::Length));
-    m.insert("km", ("kilometer", UnitType::Length));
-    m.insert("in", ("inch", UnitType::Length));
-    m.insert("ft", ("foot", UnitType::Length));
-    m.insert("yd", ("yard", UnitType::Length));
-    m.insert("mi", ("mile", UnitType::Length));
-
-    // Time (abbreviations only)
-    m.insert("s", ("second", UnitType::Time));
-    m.insert("sec", ("second", UnitType::Time));
-    m.insert("min", ("minute", UnitType::Time));
-    m.insert("h", ("hour", UnitType::Time));
-    m.insert("hr", ("hour", UnitType::Time));
-    m.insert("d", ("day", UnitType::Time));
-    m.insert("wk", ("week", UnitType::Time));
-    m.insert("mo", ("month", UnitType::Time));
-    m.insert("yr", ("year", UnitType::Time));
-
-    // Dosage forms
-    m.insert("tablet", ("tablet", UnitType::DosageForm));
-    m.insert("cap", ("capsule", UnitType::DosageForm));
-    m.insert("capsule", ("capsule", UnitType::DosageForm));
-    m.insert("supp", ("suppository", UnitType::DosageForm));
-    m.insert("amp", ("ampule", UnitType::DosageForm));
-    m.insert("vial", ("vial", UnitType::DosageForm));
-    m.insert("patch", ("patch", UnitType::DosageForm));
-    m.insert("chew", ("chew", UnitType::DosageForm));
-    m.insert("dose", ("dose", UnitType::DosageForm));
-    m.insert("sachet", ("sachet", UnitType::DosageForm));
-    m.insert("kg dose", ("kilogram dose", UnitType::DosageForm));
-    m.insert("pump", ("pump", UnitType::DosageForm));
-    m.insert("app", ("application", UnitType::DosageForm));
-    m.insert("pipette", ("pipette", UnitType::DosageForm));
-
-    // Volume aliases
-    m.insert("litre", ("litre", UnitType::Volume));
-
-    // Area
-    m.insert("sqcm", ("square centimeter", UnitType::Area));
-    m.insert("sqm", ("square meter", UnitType::Area));
-
-    // International/special units
-    m.insert("mu", ("million units", UnitType::Other));
-    m.insert("miu", ("mega international units", UnitType::Other));
-    m.insert("iu/kg", ("international units per kilogram", UnitType::Other));
-
-    // Compound rates
-    m.insert("drop/kg", ("drop per kilogram", UnitType::DosageForm));
-    m.insert("cm/sqcm", ("centimeter per square centimeter", UnitType::Area));
-    m.insert("mcg/sqcm", ("microgram per square centimeter", UnitType::Mass));
-    m.insert("mcg/sqm", ("microgram per square meter", UnitType::Mass));
-    m.insert("mg/sqm", ("milligram per square meter", UnitType::Mass));
-    m.insert("ml/sqcm", ("milliliter per square centimeter", UnitType::Volume));
-
-    // Dosage forms — general
-    m.insert("drop", ("drop", UnitType::DosageForm));
-    m.insert("syringe", ("syringe", UnitType::DosageForm));
-    m.insert("tube", ("tube", UnitType::DosageForm));
-    m.insert("actuation", ("actuation", UnitType::DosageForm));
-    m.insert("scoop", ("scoop", UnitType::DosageForm));
-    m.insert("inhaler", ("inhaler", UnitType::DosageForm));
-    m.insert("bottle", ("bottle", UnitType::DosageForm));
-    m.insert("strip", ("strip", UnitType::DosageForm));
-    m.insert("sponge", ("sponge", UnitType::DosageForm));
-    m.insert("container", ("container", UnitType::DosageForm));
-    m.insert("device", ("device", UnitType::DosageForm));
-    m.insert("cup", ("cup", UnitType::DosageForm));
-    m.insert("collar", ("collar", UnitType::DosageForm));
-    m.insert("bullet", ("bullet", UnitType::DosageForm));
-    m.insert("bolus", ("bolus", UnitType::DosageForm));
-    m.insert("ampoule", ("ampoule", UnitType::DosageForm));
-    m.insert("applicator", ("applicator", UnitType::DosageForm));
-    m.insert("course", ("course", UnitType::DosageForm));
-    m.insert("dots", ("dots", UnitType::DosageForm));
-    m.insert("measure", ("measure", UnitType::DosageForm));
-    m.insert("ear", ("ear", UnitType::DosageForm));
-    m.insert("eye", ("eye", UnitType::DosageForm));
-    m.insert("teat", ("teat", UnitType::DosageForm));
-
-    // Multi-word dosage forms (tokeniser handles these via try_compound_unit)
-    m.insert("blue scoop", ("blue scoop", UnitType::DosageForm));
-    m.insert("white scoop", ("white scoop", UnitType::DosageForm));
-    m.insert("ear tag", ("ear tag", UnitType::DosageForm));
-    m.insert("affected area", ("affected area", UnitType::DosageForm));
-    m.insert("bait unit", ("bait unit", UnitType::DosageForm));
-    m.insert("wafer strip", ("wafer strip", UnitType::DosageForm));
-    m.insert("ml/sqcm tumour", ("milliliter per square centimeter tumour", UnitType::Volume));
-
-    m
-}
-
-fn reverse_unit_map() -> HashMap<&'static str, &'static str> {
-    let mut m = HashMap::new();
-    for (abbr, (expanded, _)) in unit_map() {
-        // Insert singular
-        m.insert(expanded, abbr);
-        // Insert plural
-        let plural = crate::plural::to_plural(expanded);
-        m.insert(Box::leak(plural.into_boxed_str()), abbr);
-    }
-    m
-}
-
-fn reverse_unit_map_with_type() -> HashMap<&'static str, UnitType> {
-    let mut m = HashMap::new();
-    for (_abbr, (expanded, typ)) in unit_map() {
-        m.insert(expanded, typ);
-        let plural = crate::plural::to_plural(expanded);
-        m.insert(Box::leak(plural.into_boxed_str()), typ);
-    }
-    m
+    unit_def(unit).map(|def| def.unit_type)
+}
+
+/// Returns every accepted abbreviation for a unit, preferred spelling first.
+/// Accepts any abbreviation, alias or full name (singular or plural).
+/// E.g., "microgram" -> ["mcg", "μg"]
+pub fn aliases(unit: &str) -> Option<Vec<String>> {
+    unit_def(unit).map(|def| def.spellings().map(str::to_string).collect())
+}
+
+/// Looks up the definition for a unit abbreviation, alias or full name.
+/// Returns None if not recognized.
+pub fn unit_def(unit: &str) -> Option<Arc<UnitDef>> {
+    registry().unit_def(unit)
+}
+
+/// Looks up an abbreviation or alias, ignoring case. Where spellings differ
+/// only by case (e.g. "mm" and "Mm"), the earlier unit (millimeter) wins,
+/// unless both are written that way in lowercase ("miu").
+fn abbreviation_def(unit: &str) -> Option<Arc<UnitDef>> {
+    registry()
+        .abbreviations
+        .get(lowercase(unit).as_ref())
+        .cloned()
+}
+
+/// How letter case is treated when resolving a unit spelling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitCase {
+    /// Ignore case entirely ("ML", "ml" and "mL" are all milliliters).
+    /// Mega- and giga- units are only reachable in the other modes, and
+    /// spellings that are both a milli- and a mega- symbol ("miu" for "mIU"
+    /// and "MIU") aren't recognised at all.
+    #[default]
+    Insensitive,
+    /// Units with SI symbols only match those exact symbols, so "mg" is a
+    /// milligram but "MG" is not recognised. Other spellings and full names
+    /// are still matched case-insensitively.
+    Sensitive,
+    /// Like `Sensitive`, but falls back to `Insensitive` when there is no
+    /// exact match, flagging the result as a guess.
+    Lenient,
+}
+
+/// The outcome of resolving a unit spelling with [`resolve_unit`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitMatch {
+    pub def: Arc<UnitDef>,
+    /// True if the spelling only matched after ignoring letter case.
+    pub guessed: bool,
+}
+
+/// Resolves a unit abbreviation, alias or full name, honouring SI symbol
+/// capitalisation according to `case`.
+/// E.g., with `UnitCase::Lenient`, "mL" resolves exactly while "MG" resolves
+/// to milligram with `guessed` set.
+pub fn resolve_unit(unit: &str, case: UnitCase) -> Option<UnitMatch> {
+    registry().resolve(unit, case)
+}
+
+/// Lowercases `s`, borrowing it unchanged when it has no uppercase characters.
+fn lowercase(s: &str) -> Cow<'_, str> {
+    if s.chars().any(char::is_uppercase) {
+        Cow::Owned(s.to_lowercase())
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+/// Lowercased abbreviation or alias → definition, for every known unit.
+/// A snapshot: units registered later aren't included.
+pub fn unit_map() -> Arc<HashMap<String, Arc<UnitDef>>> {
+    registry().abbreviations.clone()
+}
+
+/// Every known unit in lookup order: registered units (most recent first),
+/// the hand-written table, then the SI-prefixed units (e.g. "ng", "kl",
+/// "MHz"). When two units share a spelling, the earlier one wins.
+/// A snapshot: units registered later aren't included.
+pub fn units() -> Arc<[Arc<UnitDef>]> {
+    registry().units.clone()
+}
+
+/// Adds units to the registry used by `expand_unit`, `unit_type`,
+/// `abbreviate_unit` and `tokenise`. Registered units take precedence over
+/// built-in units with the same spelling, and replace units registered
+/// earlier with the same abbreviation, so reloading a unit file doesn't
+/// accumulate old definitions.
+pub fn register_units(defs: impl IntoIterator<Item = UnitDef>) {
+    let mut current = registry_lock()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    let mut registered: Vec<Arc<UnitDef>> = defs.into_iter().map(Arc::new).collect();
+    let replaced: HashSet<String> = registered
+        .iter()
+        .map(|def| def.abbreviation.to_lowercase())
+        .collect();
+    registered.extend(
+        current
+            .registered
+            .iter()
+            .filter(|def| !replaced.contains(&def.abbreviation.to_lowercase()))
+            .cloned(),
+    );
+    *current = Arc::new(Registry::new(registered));
+}
+
+/// Lookup indexes over every known unit, rebuilt whenever units are registered.
+pub(crate) struct Registry {
+    /// Units added with `register_units`, most recent first.
+    registered: Vec<Arc<UnitDef>>,
+    /// Registered units followed by the built-in ones.
+    units: Arc<[Arc<UnitDef>]>,
+    /// Lowercased abbreviation or alias → definition.
+    abbreviations: Arc<HashMap<String, Arc<UnitDef>>>,
+    /// Case-exact symbol → definition.
+    symbols: HashMap<String, Arc<UnitDef>>,
+    /// Expanded name (singular and plural) → definition.
+    names: HashMap<String, Arc<UnitDef>>,
+    /// Lowercased abbreviations that aren't also full names, for `plural`.
+    lexicon: HashSet<String>,
+    /// Lowercased multi-word spellings split into words, longest first.
+    multi_word: Vec<Vec<String>>,
+}
+
+impl Registry {
+    fn new(registered: Vec<Arc<UnitDef>>) -> Self {
+        let units: Arc<[Arc<UnitDef>]> =
+            registered.iter().chain(builtin_units()).cloned().collect();
+        let mut abbreviations: HashMap<String, Arc<UnitDef>> = HashMap::new();
+        let mut ambiguous = HashSet::new();
+        let mut symbols = HashMap::new();
+        for def in units.iter() {
+            // Capitalised spellings of units with SI symbols ("Mg", "Gm") only
+            // match case-sensitively; lowercased, they would invent units
+            // ("gm" as a gigameter).
+            let case_exact = |s: &str| !def.symbols.is_empty() && s.chars().any(char::is_uppercase);
+            for spelling in def.spellings().filter(|s| !case_exact(s)) {
+                let key = spelling.to_lowercase();
+                match abbreviations.get(&key) {
+                    Some(earlier) if symbols_differ_in_case(earlier, def, &key) => {
+                        ambiguous.insert(key);
+                    }
+                    Some(_) => {}
+                    None => {
+                        abbreviations.insert(key, def.clone());
+                    }
+                }
+            }
+            for symbol in &def.symbols {
+                symbols.entry(symbol.clone()).or_insert_with(|| def.clone());
+            }
+        }
+
+        // Abbreviations with no word in them: not a unit's full name
+        // ("tablet", the "drop" in "drop/kg") or an English word ("cap").
+        let full_names: HashSet<String> = units.iter().map(|d| d.name.to_lowercase()).collect();
+        let is_word = |w: &str| full_names.contains(w) || crate::plural::is_abbreviated_word(w);
+        let lexicon: HashSet<String> = abbreviations
+            .keys()
+            .filter(|k| !k.split([' ', '/']).any(is_word))
+            .cloned()
+            .collect();
+        // Spellings whose symbols differ only in case ("miu" is both "mIU" and
+        // "MIU") can't be resolved when case is ignored.
+        abbreviations.retain(|k, _| !ambiguous.contains(k));
+
+        let mut names = HashMap::new();
+        for def in units.iter() {
+            let plural = match &def.plural {
+                Some(plural) => plural.clone(),
+                None => crate::plural::to_plural_using(&def.name, &|w| {
+                    lexicon.contains(&w.to_lowercase())
+                }),
+            };
+            names.entry(def.name.clone()).or_insert_with(|| def.clone());
+            names.entry(plural).or_insert_with(|| def.clone());
+        }
+
+        // Sorted by descending word count (longest match first), then
+        // lexicographically for determinism.
+        let mut multi_word: Vec<Vec<String>> = abbreviations
+            .keys()
+            .filter(|k| k.contains(' '))
+            .map(|k| k.split(' ').map(str::to_string).collect())
+            .collect();
+        multi_word.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+        Registry {
+            registered,
+            units,
+            abbreviations: Arc::new(abbreviations),
+            symbols,
+            names,
+            lexicon,
+            multi_word,
+        }
+    }
+
+    /// True if `abbr` (already lowercased) is a known abbreviation or alias.
+    pub(crate) fn contains(&self, abbr: &str) -> bool {
+        self.abbreviations.contains_key(abbr)
+    }
+
+    /// True if `spelling` is a known abbreviation or alias when matched
+    /// according to `case`. `Lenient` accepts what either of the other modes
+    /// does.
+    pub(crate) fn contains_with(&self, spelling: &str, case: UnitCase) -> bool {
+        // Capitalised symbols ("MHz") aren't in the lowercase index.
+        if case != UnitCase::Insensitive && self.symbols.contains_key(spelling) {
+            return true;
+        }
+        self.contains(&lowercase(spelling))
+            && (case != UnitCase::Sensitive || self.case_sensitive_def(spelling).is_some())
+    }
+
+    fn unit_def(&self, unit: &str) -> Option<Arc<UnitDef>> {
+        self.abbreviations
+            .get(lowercase(unit).as_ref())
+            .or_else(|| self.names.get(lowercase(unit.trim()).as_ref()))
+            .cloned()
+    }
+
+    fn resolve(&self, unit: &str, case: UnitCase) -> Option<UnitMatch> {
+        let exact = |def| UnitMatch {
+            def,
+            guessed: false,
+        };
+        match case {
+            UnitCase::Insensitive => self.unit_def(unit).map(exact),
+            UnitCase::Sensitive => self.case_sensitive_def(unit).map(exact),
+            UnitCase::Lenient => self.case_sensitive_def(unit).map(exact).or_else(|| {
+                self.unit_def(unit)
+                    .map(|def| UnitMatch { def, guessed: true })
+            }),
+        }
+    }
+
+    fn case_sensitive_def(&self, unit: &str) -> Option<Arc<UnitDef>> {
+        let unit = unit.trim();
+        if let Some(def) = self.symbols.get(unit) {
+            return Some(def.clone());
+        }
+        // Spellings that coincide with a symbol must match it exactly; anything
+        // else (aliases like "hr", full names) is case-insensitive.
+        let def = self.unit_def(unit)?;
+        let key = lowercase(unit);
+        if def.symbols.iter().any(|sym| sym.to_lowercase() == key) {
+            None
+        } else {
+            Some(def)
+        }
+    }
+
+    /// True if `word` (already lowercased) is the plural of a unit's name
+    /// ("milligrams", "units").
+    pub(crate) fn is_unit_plural(&self, word: &str) -> bool {
+        self.names
+            .get(word)
+            .is_some_and(|def| !def.name.eq_ignore_ascii_case(word))
+    }
+
+    /// True if `word` (already lowercased) is an abbreviation rather than a
+    /// word, so it shouldn't be inflected ("mg", "tbsp", but not "tablet").
+    pub(crate) fn is_abbreviation(&self, word: &str) -> bool {
+        self.lexicon.contains(word)
+    }
+
+    pub(crate) fn multi_word_units(&self) -> &[Vec<String>] {
+        &self.multi_word
+    }
+}
+
+/// True if `a` and `b` are different units with symbols that are both
+/// spelled `key` ignoring case ("mIU" and "MIU" for "miu").
+fn symbols_differ_in_case(a: &UnitDef, b: &UnitDef, key: &str) -> bool {
+    let symbol = |def: &UnitDef| {
+        def.symbols
+            .iter()
+            .find(|s| s.to_lowercase() == key)
+            .cloned()
+    };
+    matches!((symbol(a), symbol(b)), (Some(x), Some(y)) if x != y)
+}
+
+/// The current unit registry.
+pub(crate) fn registry() -> Arc<Registry> {
+    registry_lock()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+fn registry_lock() -> &'static RwLock<Arc<Registry>> {
+    static REGISTRY: OnceLock<RwLock<Arc<Registry>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(Arc::new(Registry::new(Vec::new()))))
+}
+
+/// The hand-written table followed by the SI-prefixed units, built once.
+fn builtin_units() -> &'static [Arc<UnitDef>] {
+    static UNITS: OnceLock<Vec<Arc<UnitDef>>> = OnceLock::new();
+    UNITS.get_or_init(|| {
+        unit_table()
+            .into_iter()
+            .chain(crate::si::prefixed_units())
+            .map(Arc::new)
+            .collect()
+    })
+}
+
+/// Built-in units that aren't generated from SI prefixes, grouped by kind.
+/// The first spelling of each entry is the one `abbreviate_unit` returns.
+fn unit_table() -> Vec<UnitDef> {
+    vec![
+        // Volume
+        UnitDef::new("cc", &[], "cubic centimeter", UnitType::Volume),
+        UnitDef::new("fl oz", &[], "fluid ounce", UnitType::Volume),
+        UnitDef::new("gal", &[], "gallon", UnitType::Volume),
+        UnitDef::new("qt", &[], "quart", UnitType::Volume),
+        UnitDef::new("pt", &[], "pint", UnitType::Volume),
+        UnitDef::new("tbsp", &[], "tablespoon", UnitType::Volume),
+        UnitDef::new("tsp", &[], "teaspoon", UnitType::Volume),
+        UnitDef::new("gtt", &["drop"], "drop", UnitType::DosageForm),
+        UnitDef::new("spray", &[], "spray", UnitType::DosageForm),
+        UnitDef::new("puff", &[], "puff", UnitType::DosageForm),
+        UnitDef::new("mg/kg", &[], "milligram per kilogram", UnitType::Mass).symbols(&["mg/kg"]),
+        UnitDef::new("ml/kg", &[], "milliliter per kilogram", UnitType::Volume)
+            .symbols(&["mL/kg", "ml/kg"]),
+        UnitDef::new(
+            "mcg/kg",
+            &["ug/kg"],
+            "microgram per kilogram",
+            UnitType::Mass,
+        )
+        .symbols(&["mcg/kg", "μg/kg"]),
+        // Mass/weight
+        UnitDef::new("lb", &[], "pound", UnitType::Mass),
+        UnitDef::new("oz", &[], "ounce", UnitType::Mass),
+        UnitDef::new("u", &[], "unit", UnitType::Other).symbols(&["U"]),
+        UnitDef::new("meq", &[], "milliequivalent", UnitType::Other).symbols(&["mEq"]),
+        // Length
+        UnitDef::new("in", &[], "inch", UnitType::Length),
+        UnitDef::new("ft", &[], "foot", UnitType::Length),
+        UnitDef::new("yd", &[], "yard", UnitType::Length),
+        UnitDef::new("mi", &[], "mile", UnitType::Length),
+        // Time (abbreviations only)
+        UnitDef::new("s", &["sec"], "second", UnitType::Time).symbols(&["s"]),
+        UnitDef::new("min", &[], "minute", UnitType::Time).symbols(&["min"]),
+        UnitDef::new("h", &["hr"], "hour", UnitType::Time).symbols(&["h"]),
+        UnitDef::new("d", &[], "day", UnitType::Time).symbols(&["d"]),
+        UnitDef::new("wk", &[], "week", UnitType::Time),
+        UnitDef::new("mo", &[], "month", UnitType::Time),
+        UnitDef::new("yr", &[], "year", UnitType::Time),
+        // Dosage forms
+        UnitDef::new("tablet", &[], "tablet", UnitType::DosageForm),
+        UnitDef::new("cap", &["capsule"], "capsule", UnitType::DosageForm),
+        UnitDef::new("supp", &[], "suppository", UnitType::DosageForm),
+        UnitDef::new("amp", &[], "ampule", UnitType::DosageForm),
+        UnitDef::new("vial", &[], "vial", UnitType::DosageForm),
+        UnitDef::new("patch", &[], "patch", UnitType::DosageForm),
+        UnitDef::new("chew", &[], "chew", UnitType::DosageForm),
+        UnitDef::new("dose", &[], "dose", UnitType::DosageForm),
+        UnitDef::new("sachet", &[], "sachet", UnitType::DosageForm),
+        UnitDef::new("kg dose", &[], "kilogram dose", UnitType::DosageForm),
+        UnitDef::new("pump", &[], "pump", UnitType::DosageForm),
+        UnitDef::new("app", &[], "application", UnitType::DosageForm),
+        UnitDef::new("pipette", &[], "pipette", UnitType::DosageForm),
+        // Volume aliases
+        UnitDef::new("litre", &[], "litre", UnitType::Volume),
+        // Area
+        UnitDef::new("sqcm", &[], "square centimeter", UnitType::Area),
+        UnitDef::new("sqm", &[], "square meter", UnitType::Area),
+        // International/special units
+        UnitDef::new("mu", &[], "million units", UnitType::Other).symbols(&["MU"]),
+        UnitDef::new("miu", &[], "mega international units", UnitType::Other).symbols(&["MIU"]),
+        UnitDef::new(
+            "iu/kg",
+            &[],
+            "international units per kilogram",
+            UnitType::Other,
+        )
+        .symbols(&["IU/kg"]),
+        // Compound rates
+        UnitDef::new("drop/kg", &[], "drop per kilogram", UnitType::DosageForm),
+        UnitDef::new(
+            "cm/sqcm",
+            &[],
+            "centimeter per square centimeter",
+            UnitType::Area,
+        ),
+        UnitDef::new(
+            "mcg/sqcm",
+            &[],
+            "microgram per square centimeter",
+            UnitType::Mass,
+        ),
+        UnitDef::new("mcg/sqm", &[], "microgram per square meter", UnitType::Mass),
+        UnitDef::new("mg/sqm", &[], "milligram per square meter", UnitType::Mass),
+        UnitDef::new(
+            "ml/sqcm",
+            &[],
+            "milliliter per square centimeter",
+            UnitType::Volume,
+        ),
+        // Dosage forms — general
+        UnitDef::new("syringe", &[], "syringe", UnitType::DosageForm),
+        UnitDef::new("tube", &[], "tube", UnitType::DosageForm),
+        UnitDef::new("actuation", &[], "actuation", UnitType::DosageForm),
+        UnitDef::new("scoop", &[], "scoop", UnitType::DosageForm),
+        UnitDef::new("inhaler", &[], "inhaler", UnitType::DosageForm),
+        UnitDef::new("bottle", &[], "bottle", UnitType::DosageForm),
+        UnitDef::new("strip", &[], "strip", UnitType::DosageForm),
+        UnitDef::new("sponge", &[], "sponge", UnitType::DosageForm),
+        UnitDef::new("container", &[], "container", UnitType::DosageForm),
+        UnitDef::new("device", &[], "device", UnitType::DosageForm),
+        UnitDef::new("cup", &[], "cup", UnitType::DosageForm),
+        UnitDef::new("collar", &[], "collar", UnitType::DosageForm),
+        UnitDef::new("bullet", &[], "bullet", UnitType::DosageForm),
+        UnitDef::new("bolus", &[], "bolus", UnitType::DosageForm),
+        UnitDef::new("ampoule", &[], "ampoule", UnitType::DosageForm),
+        UnitDef::new("applicator", &[], "applicator", UnitType::DosageForm),
+        UnitDef::new("course", &[], "course", UnitType::DosageForm),
+        UnitDef::new("dots", &[], "dots", UnitType::DosageForm),
+        UnitDef::new("measure", &[], "measure", UnitType::DosageForm),
+        UnitDef::new("ear", &[], "ear", UnitType::DosageForm),
+        UnitDef::new("eye", &[], "eye", UnitType::DosageForm),
+        UnitDef::new("teat", &[], "teat", UnitType::DosageForm),
+        // Multi-word dosage forms (tokeniser handles these via try_compound_unit)
+        UnitDef::new("blue scoop", &[], "blue scoop", UnitType::DosageForm),
+        UnitDef::new("white scoop", &[], "white scoop", UnitType::DosageForm),
+        UnitDef::new("ear tag", &[], "ear tag", UnitType::DosageForm),
+        UnitDef::new("affected area", &[], "affected area", UnitType::DosageForm),
+        UnitDef::new("bait unit", &[], "bait unit", UnitType::DosageForm),
+        UnitDef::new("wafer strip", &[], "wafer strip", UnitType::DosageForm),
+        UnitDef::new(
+            "ml/sqcm tumour",
+            &[],
+            "milliliter per square centimeter tumour",
+            UnitType::Volume,
+        ),
+    ]
 }
@@ -0,0 +1,188 @@
+//! Loads unit definitions from a simple comma-separated text file, so
+//! applications can add their own units without a crate release.
+//!
+//! The first non-comment line is a header naming the columns. `abbreviation`,
+//! `name` and `type` are required; `aliases`, `symbols`, `plural` and `factor`
+//! are optional. List columns separate their entries with `;`. Blank lines and
+//! lines starting with `#` are ignored, and empty cells mean "not set". A cell
+//! containing a comma must be quoted (`"tablet, chewable"`); a quote inside a
+//! quoted cell is written twice.
+//!
+//! ```text
+//! # Product-specific dosage forms
+//! abbreviation, name, type, aliases, plural, factor
+//! lsc, large scoop, dosage form, large scoop; lg scoop, large scoops, 5
+//! gn, grain, mass, gr, grains, 0.0648
+//! ```
+
+use crate::unit::{register_units, UnitDef, UnitType};
+use std::path::Path;
+
+const COLUMNS: [&str; 7] = [
+    "abbreviation",
+    "name",
+    "type",
+    "aliases",
+    "symbols",
+    "plural",
+    "factor",
+];
+
+/// Reads unit definitions from `path` and registers them (see
+/// [`register_units`]). Returns the number of units added.
+pub fn load_units(path: impl AsRef<Path>) -> Result<usize, String> {
+    let path = path.as_ref();
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read unit file '{}': {}", path.display(), e))?;
+    let defs = parse_units(&text)?;
+    let count = defs.len();
+    register_units(defs);
+    Ok(count)
+}
+
+/// Parses unit definitions without registering them.
+pub fn parse_units(text: &str) -> Result<Vec<UnitDef>, String> {
+    let mut header: Option<Vec<String>> = None;
+    let mut defs = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let cells = split_cells(line).map_err(|e| format!("line {}: {}", line_no, e))?;
+        match &header {
+            None => header = Some(parse_header(cells, line_no)?),
+            Some(columns) => defs.push(parse_row(columns, &cells, line_no)?),
+        }
+    }
+    Ok(defs)
+}
+
+/// Splits a line into trimmed cells at commas outside double quotes.
+fn split_cells(line: &str) -> Result<Vec<String>, &'static str> {
+    let mut cells = Vec::new();
+    let mut rest = line;
+    loop {
+        let cell;
+        (cell, rest) = match rest.trim_start().strip_prefix('"') {
+            Some(quoted) => {
+                let (cell, after) = quoted_cell(quoted)?;
+                (cell, after.trim_start())
+            }
+            None => {
+                let end = rest.find(',').unwrap_or(rest.len());
+                (rest[..end].trim().to_string(), &rest[end..])
+            }
+        };
+        cells.push(cell);
+        match rest.strip_prefix(',') {
+            Some(after) => rest = after,
+            None if rest.is_empty() => return Ok(cells),
+            None => return Err("text after a closing quote"),
+        }
+    }
+}
+
+/// Reads a quoted cell up to its closing quote, given the text after the
+/// opening one. Returns the cell and the text after the closing quote.
+fn quoted_cell(s: &str) -> Result<(String, &str), &'static str> {
+    let mut cell = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c != '"' {
+            cell.push(c);
+        } else if s[i + 1..].starts_with('"') {
+            chars.next();
+            cell.push('"');
+        } else {
+            return Ok((cell, &s[i + 1..]));
+        }
+    }
+    Err("unterminated quote")
+}
+
+fn parse_header(cells: Vec<String>, line_no: usize) -> Result<Vec<String>, String> {
+    for cell in &cells {
+        if !COLUMNS.contains(&cell.as_str()) {
+            return Err(format!("line {}: unknown column '{}'", line_no, cell));
+        }
+    }
+    for required in &COLUMNS[..3] {
+        if !cells.iter().any(|c| c == required) {
+            return Err(format!("line {}: missing column '{}'", line_no, required));
+        }
+    }
+    Ok(cells)
+}
+
+fn parse_row(columns: &[String], cells: &[String], line_no: usize) -> Result<UnitDef, String> {
+    if cells.len() > columns.len() {
+        return Err(format!(
+            "line {}: expected at most {} cells, found {}",
+            line_no,
+            columns.len(),
+            cells.len()
+        ));
+    }
+    let cell = |column: &str| -> Option<&str> {
+        let idx = columns.iter().position(|c| *c == column)?;
+        cells.get(idx).map(String::as_str).filter(|c| !c.is_empty())
+    };
+    let required = |column: &str| -> Result<String, String> {
+        cell(column)
+            .map(str::to_string)
+            .ok_or_else(|| format!("line {}: missing {}", line_no, column))
+    };
+    let list = |column: &str| -> Vec<String> {
+        cell(column)
+            .map(|c| {
+                c.split(';')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let type_name = required("type")?;
+    let unit_type = parse_unit_type(&type_name)
+        .ok_or_else(|| format!("line {}: unknown unit type '{}'", line_no, type_name))?;
+    let factor = match cell("factor") {
+        Some(f) => Some(
+            f.parse::<f64>()
+                .map_err(|_| format!("line {}: invalid factor '{}'", line_no, f))?,
+        ),
+        None => None,
+    };
+    Ok(UnitDef {
+        abbreviation: required("abbreviation")?,
+        aliases: list("aliases"),
+        name: required("name")?,
+        unit_type,
+        symbols: list("symbols"),
+        plural: cell("plural").map(str::to_string),
+        factor,
+    })
+}
+
+/// Accepts type names in any case, with or without separators
+/// (e.g. "dosage form", "DosageForm", "dosage_form").
+fn parse_unit_type(s: &str) -> Option<UnitType> {
+    let key: String = s
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect();
+    match key.as_str() {
+        "volume" => Some(UnitType::Volume),
+        "mass" => Some(UnitType::Mass),
+        "length" => Some(UnitType::Length),
+        "time" => Some(UnitType::Time),
+        "area" => Some(UnitType::Area),
+        "dosageform" => Some(UnitType::DosageForm),
+        "other" => Some(UnitType::Other),
+        _ => None,
+    }
+}
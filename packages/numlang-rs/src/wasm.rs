@@ -0,0 +1,156 @@
+//! WebAssembly bindings with the same names and argument shapes as
+//! numlang.js, so either can back the docs demo. Build with the `wasm`
+//! feature for `wasm32-unknown-unknown` and run `wasm-bindgen` on the output.
+
+use crate::tokenise::{Token, TokenSpan};
+use crate::{from_words, to_ordinal, to_words, to_words_ordinal, tokenise, Case, Options};
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+/// `tokenise`, with `start` and `end` counted in UTF-16 code units, as
+/// JavaScript indexes strings.
+pub(crate) fn tokenise_utf16(input: &str) -> Vec<TokenSpan> {
+    let mut spans = tokenise(input);
+    // Offsets only move forward, so count from the previous one.
+    let (mut byte, mut unit) = (0, 0);
+    let mut to_utf16 = |offset: usize| {
+        if offset < byte {
+            (byte, unit) = (0, 0);
+        }
+        unit += input[byte..offset].encode_utf16().count();
+        byte = offset;
+        unit
+    };
+    for span in &mut spans {
+        span.start = to_utf16(span.start);
+        span.end = to_utf16(span.end);
+    }
+    spans
+}
+
+/// Reads numlang.js options (`{ useCommas, useAnd, appendOnly, uppercase,
+/// capitalize }`), plus `case` ("lower", "upper", "sentence", "title" or
+/// "preserve"), which overrides `uppercase` and `capitalize`.
+fn options(value: &JsValue) -> Result<Options, JsError> {
+    if value.is_undefined() || value.is_null() {
+        return Ok(Options::new());
+    }
+    let get =
+        |key: &str| Reflect::get(value, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED);
+    let flag = |key: &str| get(key).as_bool().unwrap_or(false);
+    let case = match get("case").as_string().as_deref() {
+        Some("lower") => Case::Lower,
+        Some("upper") => Case::Upper,
+        Some("sentence") => Case::SentenceCase,
+        Some("title") => Case::TitleCase,
+        Some("preserve") => Case::Preserve,
+        Some(other) => return Err(JsError::new(&format!("Unknown case: {}", other))),
+        None if flag("uppercase") => Case::Upper,
+        None if flag("capitalize") => Case::SentenceCase,
+        None => Case::Preserve,
+    };
+    Ok(Options::new()
+        .use_commas(flag("useCommas"))
+        .use_and(flag("useAnd"))
+        .append_only(flag("appendOnly"))
+        .case(case))
+}
+
+/// The `i64` equal to `number`, or None for fractions, NaN, infinities and
+/// values outside `i64`. `i64::MAX as f64` rounds up to 2^63, which doesn't
+/// fit, so the upper bound is exclusive.
+pub(crate) fn integer(number: f64) -> Option<i64> {
+    if number.fract() == 0.0 && number >= i64::MIN as f64 && number < i64::MAX as f64 {
+        Some(number as i64)
+    } else {
+        None
+    }
+}
+
+fn ordinal_integer(number: f64) -> Result<i64, JsError> {
+    integer(number).ok_or_else(|| JsError::new("Ordinals only work with integers"))
+}
+
+/// Throws for NaN and infinities, as numlang.js does.
+#[wasm_bindgen(js_name = toWords)]
+pub fn to_words_js(number: f64, options: JsValue) -> Result<String, JsError> {
+    if !number.is_finite() {
+        return Err(JsError::new("Invalid number provided"));
+    }
+    Ok(to_words(number, &self::options(&options)?))
+}
+
+#[wasm_bindgen(js_name = toOrdinal)]
+pub fn to_ordinal_js(number: f64) -> Result<String, JsError> {
+    Ok(to_ordinal(ordinal_integer(number)?))
+}
+
+#[wasm_bindgen(js_name = toWordsOrdinal)]
+pub fn to_words_ordinal_js(number: f64, options: JsValue) -> Result<String, JsError> {
+    Ok(to_words_ordinal(
+        ordinal_integer(number)?,
+        &self::options(&options)?,
+    ))
+}
+
+/// Throws an `Error` with the parse error as its message.
+#[wasm_bindgen(js_name = fromWords)]
+pub fn from_words_js(s: &str) -> Result<f64, JsError> {
+    from_words(s).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Returns `{ token: { type, value }, start, end }` objects, with positions
+/// in UTF-16 code units.
+#[wasm_bindgen(js_name = tokenise)]
+pub fn tokenise_js(input: &str) -> Array {
+    let set = |object: &Object, key: &str, value: JsValue| {
+        // Setting a property on a plain object can't fail.
+        let _ = Reflect::set(object, &JsValue::from_str(key), &value);
+    };
+    tokenise_utf16(input)
+        .into_iter()
+        .map(|span| {
+            let (kind, value) = match span.token {
+                Token::NumberWord(s) => ("NumberWord", s),
+                Token::NumberString(s) => ("NumberString", s),
+                Token::Unit(s) => ("Unit", s),
+                Token::Unknown(s) => ("Unknown", s),
+            };
+            let token = Object::new();
+            set(&token, "type", kind.into());
+            set(&token, "value", value.into());
+            let object = Object::new();
+            set(&object, "token", token.into());
+            set(&object, "start", (span.start as u32).into());
+            set(&object, "end", (span.end as u32).into());
+            JsValue::from(object)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenise_utf16_offsets() {
+        // "½" is two bytes but one UTF-16 unit; "🙂" is four bytes, two units.
+        let spans = tokenise_utf16("½ 🙂 2 mg");
+        let positions: Vec<_> = spans.iter().map(|s| (s.start, s.end)).collect();
+        assert_eq!(positions, vec![(0, 1), (2, 4), (5, 6), (7, 9)]);
+        assert_eq!(spans[2].token, Token::NumberString("2".to_string()));
+        let ascii = tokenise_utf16("200g");
+        assert_eq!((ascii[1].start, ascii[1].end), (3, 4));
+    }
+
+    #[test]
+    fn test_ordinal_integer_bounds() {
+        assert_eq!(integer(21.0), Some(21));
+        assert_eq!(integer(-3.0), Some(-3));
+        assert_eq!(integer(i64::MIN as f64), Some(i64::MIN));
+        assert_eq!(integer(i64::MAX as f64), None);
+        assert_eq!(integer(1.5), None);
+        assert_eq!(integer(f64::NAN), None);
+        assert_eq!(integer(f64::INFINITY), None);
+    }
+}
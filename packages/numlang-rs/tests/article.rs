@@ -0,0 +1,63 @@
+#![cfg(feature = "std")]
+
+use numlang::indefinite_article;
+
+#[test]
+fn test_numbers() {
+    assert_eq!(indefinite_article("8 mg tablet"), "an");
+    assert_eq!(indefinite_article("1 ml dose"), "a");
+    assert_eq!(indefinite_article("11-year-old"), "an");
+    assert_eq!(indefinite_article("18 gauge needle"), "an");
+    assert_eq!(indefinite_article("80 kg calf"), "an");
+    assert_eq!(indefinite_article("100 mg vial"), "a");
+    assert_eq!(indefinite_article("8.5 cm incision"), "an");
+    assert_eq!(indefinite_article("8th dose"), "an");
+    assert_eq!(indefinite_article("1,000 unit vial"), "a");
+}
+
+#[test]
+fn test_unit_abbreviations() {
+    assert_eq!(indefinite_article("ml"), "an");
+    assert_eq!(indefinite_article("mg dose"), "an");
+    assert_eq!(indefinite_article("kg"), "a");
+    assert_eq!(indefinite_article("IU"), "an");
+    assert_eq!(indefinite_article("tbsp"), "a");
+    assert_eq!(indefinite_article("oz"), "an");
+    assert_eq!(indefinite_article("tab"), "a");
+}
+
+#[test]
+fn test_words() {
+    assert_eq!(indefinite_article("tablet"), "a");
+    assert_eq!(indefinite_article("ointment"), "an");
+    assert_eq!(indefinite_article("hour"), "an");
+    assert_eq!(indefinite_article("unit"), "a");
+    assert_eq!(indefinite_article("udder"), "an");
+    assert_eq!(indefinite_article("ewe"), "a");
+    assert_eq!(indefinite_article("one-off"), "a");
+    assert_eq!(indefinite_article("Eye drop"), "an");
+}
+
+#[test]
+fn test_un_prefix() {
+    for word in [
+        "uniform dose",
+        "union",
+        "unique",
+        "universal",
+        "unilateral",
+        "unisex",
+    ] {
+        assert_eq!(indefinite_article(word), "a", "{}", word);
+    }
+    for word in ["unidentified", "uninsured", "unimportant", "unopened vial"] {
+        assert_eq!(indefinite_article(word), "an", "{}", word);
+    }
+}
+
+#[test]
+fn test_initialisms() {
+    assert_eq!(indefinite_article("NSAID"), "an");
+    assert_eq!(indefinite_article("UTI"), "a");
+    assert_eq!(indefinite_article("X-ray"), "an");
+}
@@ -1,4 +1,4 @@
-use numlang::{to_words, Options};
+use numlang::{to_words, Case, Options};
 
 #[test]
 fn test_zero() {
@@ -50,19 +50,25 @@ fn test_formatting_options() {
     );
 
     opts = Options::default();
-    opts.uppercase = true;
+    opts.case = Case::Upper;
     assert_eq!(
         to_words(1234, &opts),
         "ONE THOUSAND TWO HUNDRED THIRTY-FOUR"
     );
 
     opts = Options::default();
-    opts.capitalize = true;
+    opts.case = Case::SentenceCase;
     assert_eq!(
         to_words(1234, &opts),
         "One thousand two hundred thirty-four"
     );
 
+    opts = Options::new().use_and(true).case(Case::TitleCase);
+    assert_eq!(
+        to_words(1221, &opts),
+        "One Thousand Two Hundred and Twenty-One"
+    );
+
     opts = Options::default();
     opts.append_only = true;
     assert_eq!(
@@ -79,3 +85,27 @@ fn test_leading_zero_in_decimal() {
         "zero point one zero five"
     );
 }
+
+#[test]
+fn test_write_words() {
+    use numlang::{write_words, Words};
+    let mut out = String::from("Dose: ");
+    let opts = Options::new().case(Case::Upper);
+    write_words(&mut out, 42, &opts).unwrap();
+    assert_eq!(out, "Dose: FORTY-TWO");
+    assert_eq!(format!("{} tablets", Words(2)), "two tablets");
+    for n in [0.0, -7.25, 1e21, 1e35, 1e40, f64::MAX, 5e-324] {
+        let opts = Options::new().use_commas(true).use_and(true);
+        assert_eq!(Words(n).with(&opts).to_string(), to_words(n, &opts));
+    }
+}
+
+#[test]
+fn test_non_finite() {
+    let opts = Options::default();
+    assert_eq!(to_words(f64::NAN, &opts), "not a number");
+    assert_eq!(to_words(f64::INFINITY, &opts), "infinity");
+    assert_eq!(to_words(f64::NEG_INFINITY, &opts), "negative infinity");
+    let opts = Options::new().case(Case::Upper).append_only(true);
+    assert_eq!(to_words(f64::INFINITY, &opts), "INFINITY ONLY");
+}
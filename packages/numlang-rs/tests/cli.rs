@@ -0,0 +1,97 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn numlang(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_numlang"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_words_and_ordinal_args() {
+    let out = numlang(&["words", "--and", "--case", "title", "121", "-5"], "");
+    assert!(out.status.success());
+    assert_eq!(stdout(&out), "One Hundred and Twenty-One\nNegative Five\n");
+    let out = numlang(&["ordinal", "3", "12"], "");
+    assert_eq!(stdout(&out), "third\ntwelfth\n");
+    let out = numlang(&["ordinal", "--digits", "22"], "");
+    assert_eq!(stdout(&out), "22nd\n");
+}
+
+#[test]
+fn test_parse_stdin_json() {
+    let out = numlang(&["parse", "--json"], "two and a half\nbanana\n");
+    assert!(!out.status.success());
+    assert_eq!(
+        stdout(&out),
+        "{\"input\":\"two and a half\",\"value\":2.5}\n\
+         {\"error\":\"position 0: Unknown token: banana\",\"input\":\"banana\"}\n"
+    );
+    let out = numlang(&["parse", "--exact", "--strict"], "two thirds\n");
+    assert_eq!(stdout(&out), "2/3\n");
+}
+
+#[test]
+fn test_tokenise_and_units() {
+    let out = numlang(&["tokenise", "--json", "200g"], "");
+    assert_eq!(
+        stdout(&out),
+        "{\"input\":\"200g\",\"tokens\":[\
+         {\"end\":3,\"start\":0,\"text\":\"200\",\"type\":\"NumberString\"},\
+         {\"end\":4,\"start\":3,\"text\":\"g\",\"type\":\"Unit\"}]}\n"
+    );
+    let out = numlang(&["units"], "mg\n");
+    assert_eq!(stdout(&out), "mg\tmilligram\tmilligrams\tMass\n");
+    let out = numlang(&["units", "iu", "meq"], "");
+    assert_eq!(
+        stdout(&out),
+        "IU\tinternational unit\tinternational units\tOther\n\
+         mEq\tmilliequivalent\tmilliequivalents\tOther\n"
+    );
+}
+
+#[test]
+fn test_parse_rejects_non_finite() {
+    let out = numlang(&["parse", "--json", "1e400", "nan", "two"], "");
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(
+        stdout(&out),
+        "{\"error\":\"not a finite number\",\"input\":\"1e400\"}\n\
+         {\"error\":\"not a finite number\",\"input\":\"nan\"}\n\
+         {\"input\":\"two\",\"value\":2.0}\n"
+    );
+}
+
+#[test]
+fn test_words_rejects_non_finite() {
+    let out = numlang(&["words", "1e400", "NaN", "-inf", "5"], "");
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(stdout(&out), "five\n");
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert_eq!(stderr.matches("not a finite number").count(), 3);
+}
+
+#[test]
+fn test_usage_errors() {
+    let out = numlang(&["bogus"], "");
+    assert_eq!(out.status.code(), Some(2));
+    let out = numlang(&["parse", "--and"], "");
+    assert_eq!(out.status.code(), Some(2));
+}
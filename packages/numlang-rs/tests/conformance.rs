@@ -0,0 +1,165 @@
+//! Runs the shared fixture corpus in `conformance/` at the repository root.
+//! Every failing case is listed, so one run shows all divergences.
+
+#![cfg(feature = "std")]
+
+use numlang::{
+    abbreviate_unit, expand_unit, from_words, to_ordinal, to_plural, to_singular, to_words,
+    to_words_ordinal, tokenise, Case, Options, Token,
+};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../conformance");
+
+/// Runs each case in `<function>.json` through `run`, which returns the
+/// output as JSON, or Err for a failed call.
+fn check(function: &str, run: impl Fn(&Value, &Options) -> Result<Value, String>) {
+    let path = Path::new(FIXTURES).join(format!("{}.json", function));
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    let fixture: Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(fixture["function"], function, "{}", path.display());
+    let mut failures = Vec::new();
+    for (i, case) in fixture["cases"].as_array().unwrap().iter().enumerate() {
+        if let Some(reason) = case["skip"]["rust"].as_str() {
+            eprintln!("{} case {}: skipped: {}", function, i, reason);
+            continue;
+        }
+        let input = &case["input"];
+        let actual = run(input, &options(&case["options"]));
+        let passed = match (&actual, case["error"].as_bool().unwrap_or(false)) {
+            (Err(_), true) => true,
+            (Ok(actual), false) => same(actual, &case["expected"]),
+            _ => false,
+        };
+        if !passed {
+            let expected = match case["error"].as_bool() {
+                Some(true) => json!("an error"),
+                _ => case["expected"].clone(),
+            };
+            failures.push(format!(
+                "  case {}: input {}, expected {}, got {:?}",
+                i, input, expected, actual
+            ));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} diverges from the fixtures:\n{}",
+        function,
+        failures.join("\n")
+    );
+}
+
+/// Numbers are compared as `f64`, so 42 and 42.0 match.
+fn same(actual: &Value, expected: &Value) -> bool {
+    match (actual.as_f64(), expected.as_f64()) {
+        (Some(a), Some(e)) => a == e,
+        _ => actual == expected,
+    }
+}
+
+fn options(value: &Value) -> Options {
+    let flag = |name: &str| value[name].as_bool().unwrap_or(false);
+    let case = match value["case"].as_str() {
+        None | Some("preserve") => Case::Preserve,
+        Some("lower") => Case::Lower,
+        Some("upper") => Case::Upper,
+        Some("sentence") => Case::SentenceCase,
+        Some("title") => Case::TitleCase,
+        Some(other) => panic!("unknown case '{}'", other),
+    };
+    Options::new()
+        .use_commas(flag("use_commas"))
+        .use_and(flag("use_and"))
+        .append_only(flag("append_only"))
+        .case(case)
+}
+
+fn number(input: &Value) -> f64 {
+    input.as_f64().expect("numeric input")
+}
+
+fn integer(input: &Value) -> i64 {
+    input.as_i64().expect("integer input")
+}
+
+fn text(input: &Value) -> &str {
+    input.as_str().expect("string input")
+}
+
+#[test]
+fn test_to_words() {
+    check("to_words", |input, opts| {
+        Ok(json!(to_words(number(input), opts)))
+    });
+}
+
+#[test]
+fn test_to_ordinal() {
+    check("to_ordinal", |input, _| {
+        Ok(json!(to_ordinal(integer(input))))
+    });
+}
+
+#[test]
+fn test_to_words_ordinal() {
+    check("to_words_ordinal", |input, opts| {
+        Ok(json!(to_words_ordinal(integer(input), opts)))
+    });
+}
+
+#[test]
+fn test_from_words() {
+    check("from_words", |input, _| {
+        from_words(text(input))
+            .map(|v| json!(v))
+            .map_err(|e| e.to_string())
+    });
+}
+
+#[test]
+fn test_tokenise() {
+    check("tokenise", |input, _| {
+        let tokens: Vec<Value> = tokenise(text(input))
+            .into_iter()
+            .map(|span| {
+                let (kind, value) = match span.token {
+                    Token::NumberWord(s) => ("NumberWord", s),
+                    Token::NumberString(s) => ("NumberString", s),
+                    Token::Unit(s) => ("Unit", s),
+                    Token::Unknown(s) => ("Unknown", s),
+                };
+                json!({ "type": kind, "value": value, "start": span.start, "end": span.end })
+            })
+            .collect();
+        Ok(json!(tokens))
+    });
+}
+
+#[test]
+fn test_expand_unit() {
+    check("expand_unit", |input, _| {
+        Ok(json!(expand_unit(text(input))))
+    });
+}
+
+#[test]
+fn test_abbreviate_unit() {
+    check("abbreviate_unit", |input, _| {
+        Ok(json!(abbreviate_unit(text(input))))
+    });
+}
+
+#[test]
+fn test_to_plural() {
+    check("to_plural", |input, _| Ok(json!(to_plural(text(input)))));
+}
+
+#[test]
+fn test_to_singular() {
+    check("to_singular", |input, _| {
+        Ok(json!(to_singular(text(input))))
+    });
+}
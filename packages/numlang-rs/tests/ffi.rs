@@ -0,0 +1,93 @@
+//! Builds numlang as a static library, compiles `tests/ffi/harness.c`
+//! against `include/numlang.h` and runs it. The harness also checks the
+//! header's types have the layout of the `#[repr(C)]` Rust ones.
+
+#![cfg(all(feature = "ffi", unix))]
+
+use numlang::ffi::{COptions, CTokenKind, CTokenSpan, CTokens, Status};
+use std::mem::{align_of, offset_of, size_of};
+use std::path::Path;
+use std::process::Command;
+
+/// `-D` flags giving the size, alignment and field offsets of each Rust
+/// type the header declares.
+fn layout_defines() -> Vec<String> {
+    [
+        ("STATUS_SIZE", size_of::<Status>()),
+        ("TOKEN_KIND_SIZE", size_of::<CTokenKind>()),
+        ("OPTIONS_SIZE", size_of::<COptions>()),
+        ("OPTIONS_ALIGN", align_of::<COptions>()),
+        ("OPTIONS_USE_COMMAS", offset_of!(COptions, use_commas)),
+        ("OPTIONS_USE_AND", offset_of!(COptions, use_and)),
+        ("OPTIONS_APPEND_ONLY", offset_of!(COptions, append_only)),
+        ("OPTIONS_LETTER_CASE", offset_of!(COptions, letter_case)),
+        ("TOKEN_SPAN_SIZE", size_of::<CTokenSpan>()),
+        ("TOKEN_SPAN_ALIGN", align_of::<CTokenSpan>()),
+        ("TOKEN_SPAN_KIND", offset_of!(CTokenSpan, kind)),
+        ("TOKEN_SPAN_TEXT", offset_of!(CTokenSpan, text)),
+        ("TOKEN_SPAN_START", offset_of!(CTokenSpan, start)),
+        ("TOKEN_SPAN_END", offset_of!(CTokenSpan, end)),
+        ("TOKENS_SIZE", size_of::<CTokens>()),
+        ("TOKENS_ALIGN", align_of::<CTokens>()),
+        ("TOKENS_SPANS", offset_of!(CTokens, spans)),
+        ("TOKENS_LEN", offset_of!(CTokens, len)),
+    ]
+    .iter()
+    .map(|(name, value)| format!("-DRUST_{}={}", name, value))
+    .collect()
+}
+
+#[test]
+fn test_c_harness() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // Not the default target directory, which `cargo test` has locked.
+    let target_dir = root.join("target").join("ffi-harness");
+    let status = Command::new(env!("CARGO"))
+        .current_dir(root)
+        .args([
+            "rustc",
+            "--lib",
+            "--features",
+            "ffi",
+            "--crate-type",
+            "staticlib",
+        ])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "building the static library failed");
+
+    let rustc = Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+        .arg("-vV")
+        .output()
+        .unwrap();
+    let rustc = String::from_utf8(rustc.stdout).unwrap();
+    let host = rustc
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .unwrap();
+    let compiler = cc::Build::new()
+        .host(host)
+        .target(host)
+        .opt_level(0)
+        .cargo_metadata(false)
+        .get_compiler();
+
+    let exe = target_dir.join("harness");
+    let status = compiler
+        .to_command()
+        .arg("-I")
+        .arg(root.join("include"))
+        .args(layout_defines())
+        .arg(root.join("tests/ffi/harness.c"))
+        .arg(target_dir.join("debug/libnumlang.a"))
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&exe)
+        .status()
+        .unwrap();
+    assert!(status.success(), "compiling the C harness failed");
+
+    let status = Command::new(&exe).status().unwrap();
+    assert!(status.success(), "the C harness reported failures");
+}
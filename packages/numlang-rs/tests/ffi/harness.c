@@ -0,0 +1,139 @@
+/* Exercises the C interface through include/numlang.h. Run by tests/ffi.rs. */
+#include <stdio.h>
+#include <string.h>
+
+#include "numlang.h"
+
+/* The header's types must match the Rust ones, whose layout tests/ffi.rs
+ * passes in as RUST_* definitions. */
+#define SAME_LAYOUT(c, rust) _Static_assert((c) == (rust), #c " differs from Rust")
+
+SAME_LAYOUT(sizeof(numlang_status), RUST_STATUS_SIZE);
+SAME_LAYOUT(sizeof(numlang_token_kind), RUST_TOKEN_KIND_SIZE);
+
+SAME_LAYOUT(sizeof(numlang_options), RUST_OPTIONS_SIZE);
+SAME_LAYOUT(_Alignof(numlang_options), RUST_OPTIONS_ALIGN);
+SAME_LAYOUT(offsetof(numlang_options, use_commas), RUST_OPTIONS_USE_COMMAS);
+SAME_LAYOUT(offsetof(numlang_options, use_and), RUST_OPTIONS_USE_AND);
+SAME_LAYOUT(offsetof(numlang_options, append_only), RUST_OPTIONS_APPEND_ONLY);
+SAME_LAYOUT(offsetof(numlang_options, letter_case), RUST_OPTIONS_LETTER_CASE);
+
+SAME_LAYOUT(sizeof(numlang_token_span), RUST_TOKEN_SPAN_SIZE);
+SAME_LAYOUT(_Alignof(numlang_token_span), RUST_TOKEN_SPAN_ALIGN);
+SAME_LAYOUT(offsetof(numlang_token_span, kind), RUST_TOKEN_SPAN_KIND);
+SAME_LAYOUT(offsetof(numlang_token_span, text), RUST_TOKEN_SPAN_TEXT);
+SAME_LAYOUT(offsetof(numlang_token_span, start), RUST_TOKEN_SPAN_START);
+SAME_LAYOUT(offsetof(numlang_token_span, end), RUST_TOKEN_SPAN_END);
+
+SAME_LAYOUT(sizeof(numlang_tokens), RUST_TOKENS_SIZE);
+SAME_LAYOUT(_Alignof(numlang_tokens), RUST_TOKENS_ALIGN);
+SAME_LAYOUT(offsetof(numlang_tokens, spans), RUST_TOKENS_SPANS);
+SAME_LAYOUT(offsetof(numlang_tokens, len), RUST_TOKENS_LEN);
+
+static int failures = 0;
+
+#define CHECK(cond)                                                     \
+    do {                                                                \
+        if (!(cond)) {                                                  \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,      \
+                    __LINE__, #cond);                                   \
+            failures++;                                                 \
+        }                                                               \
+    } while (0)
+
+static void check_string(char *actual, const char *expected) {
+    if (actual == NULL || strcmp(actual, expected) != 0) {
+        fprintf(stderr, "expected \"%s\", got \"%s\"\n", expected,
+                actual ? actual : "(null)");
+        failures++;
+    }
+    numlang_string_free(actual);
+}
+
+static void test_words(void) {
+    numlang_options options = {0};
+    check_string(numlang_to_words(42, NULL), "forty-two");
+    check_string(numlang_to_words(-0.5, &options), "negative zero point five");
+
+    options.use_and = 1;
+    options.use_commas = 2;
+    options.letter_case = NUMLANG_CASE_TITLE;
+    check_string(numlang_to_words(1221, &options),
+                 "One Thousand, Two Hundred and Twenty-One");
+
+    options.letter_case = NUMLANG_CASE_UPPER;
+    options.append_only = 0xff;
+    check_string(numlang_to_words_ordinal(21, &options), "TWENTY-FIRST ONLY");
+    check_string(numlang_to_ordinal(22), "22nd");
+
+    options.letter_case = 99;
+    CHECK(numlang_to_words(1, &options) == NULL);
+}
+
+static void test_from_words(void) {
+    double value = 0;
+    char *error = NULL;
+
+    CHECK(numlang_from_words("two and a half", false, &value, NULL) == NUMLANG_OK);
+    CHECK(value == 2.5);
+    CHECK(numlang_from_words("12.5", true, &value, NULL) == NUMLANG_OK);
+    CHECK(value == 12.5);
+
+    CHECK(numlang_from_words("two bananas", false, &value, &error) ==
+          NUMLANG_ERR_UNKNOWN_WORD);
+    CHECK(error != NULL && strstr(error, "bananas") != NULL);
+    numlang_string_free(error);
+
+    CHECK(numlang_from_words("one point five x", false, &value, NULL) ==
+          NUMLANG_ERR_UNKNOWN_WORD);
+    CHECK(numlang_from_words("one point ten", false, &value, NULL) ==
+          NUMLANG_ERR_INVALID_DECIMAL);
+    CHECK(numlang_from_words("one one", true, &value, NULL) ==
+          NUMLANG_ERR_MALFORMED);
+    CHECK(numlang_from_words("one one", false, &value, NULL) == NUMLANG_OK);
+    CHECK(numlang_from_words("one point", true, &value, NULL) ==
+          NUMLANG_ERR_MALFORMED);
+    CHECK(numlang_from_words("one hundred hundred hundred hundred hundred "
+                             "hundred hundred hundred hundred hundred hundred "
+                             "hundred hundred hundred hundred hundred hundred "
+                             "hundred hundred hundred",
+                             false, &value, NULL) == NUMLANG_ERR_OVERFLOW);
+    CHECK(numlang_from_words("\xff", false, &value, NULL) ==
+          NUMLANG_ERR_INVALID_UTF8);
+    CHECK(numlang_from_words(NULL, false, &value, NULL) ==
+          NUMLANG_ERR_NULL_ARGUMENT);
+}
+
+static void test_tokenise(void) {
+    numlang_tokens tokens;
+    CHECK(numlang_tokenise("Give 200mg", &tokens) == NUMLANG_OK);
+    CHECK(tokens.len == 3);
+    if (tokens.len == 3) {
+        CHECK(tokens.spans[0].kind == NUMLANG_TOKEN_UNKNOWN);
+        CHECK(strcmp(tokens.spans[0].text, "Give") == 0);
+        CHECK(tokens.spans[1].kind == NUMLANG_TOKEN_NUMBER_STRING);
+        CHECK(tokens.spans[1].start == 5 && tokens.spans[1].end == 8);
+        CHECK(tokens.spans[2].kind == NUMLANG_TOKEN_UNIT);
+        CHECK(strcmp(tokens.spans[2].text, "mg") == 0);
+        CHECK(tokens.spans[2].start == 8 && tokens.spans[2].end == 10);
+    }
+    numlang_tokens_free(&tokens);
+    CHECK(tokens.spans == NULL && tokens.len == 0);
+    /* Freeing twice is harmless once emptied. */
+    numlang_tokens_free(&tokens);
+
+    CHECK(numlang_tokenise("", &tokens) == NUMLANG_OK);
+    CHECK(tokens.len == 0);
+    numlang_tokens_free(&tokens);
+}
+
+int main(void) {
+    test_words();
+    test_from_words();
+    test_tokenise();
+    if (failures > 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    return 0;
+}
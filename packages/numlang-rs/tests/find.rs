@@ -0,0 +1,75 @@
+#![cfg(feature = "std")]
+
+use numlang::find_numbers;
+
+fn values(text: &str) -> Vec<f64> {
+    find_numbers(text).into_iter().map(|(v, _)| v).collect()
+}
+
+fn spans(text: &str) -> Vec<&str> {
+    find_numbers(text)
+        .into_iter()
+        .map(|(_, range)| &text[range])
+        .collect()
+}
+
+#[test]
+fn test_several_numbers() {
+    let text = "give two tablets then one";
+    assert_eq!(find_numbers(text), vec![(2.0, 5..8), (1.0, 22..25)]);
+    assert_eq!(values("Take 2.5 ml, then 10 ml"), vec![2.5, 10.0]);
+    assert_eq!(values("one and two"), vec![1.0, 2.0]);
+}
+
+#[test]
+fn test_number_phrases() {
+    let text = "Inject two hundred and five units on day twenty-one.";
+    assert_eq!(values(text), vec![205.0, 21.0]);
+    assert_eq!(spans(text), vec!["two hundred and five", "twenty-one"]);
+    assert_eq!(values("negative forty-two degrees"), vec![-42.0]);
+}
+
+#[test]
+fn test_fractions() {
+    let text = "Give one and a half tablets, then a half, then three quarters";
+    assert_eq!(values(text), vec![1.5, 0.5, 0.75]);
+    assert_eq!(
+        spans(text),
+        vec!["one and a half", "a half", "three quarters"]
+    );
+    assert_eq!(values("take 1/2 or 1½ tablets"), vec![0.5, 1.5]);
+    assert_eq!(values("two and three quarters"), vec![2.75]);
+}
+
+#[test]
+fn test_no_numbers() {
+    assert!(find_numbers("").is_empty());
+    assert!(find_numbers("Twice daily").is_empty());
+    assert!(find_numbers("salt and pepper, a tablet").is_empty());
+}
+
+#[test]
+fn test_value_unit_combos() {
+    let text = "Give 200mg.";
+    assert_eq!(find_numbers(text), vec![(200.0, 5..8)]);
+}
+
+#[test]
+fn test_comma_grouped_numbers() {
+    assert_eq!(find_numbers("1,000 mg"), vec![(1000.0, 0..5)]);
+    assert_eq!(values("Give 1,250,000.5 units"), vec![1250000.5]);
+    assert_eq!(values("1, 2, 3"), vec![1.0, 2.0, 3.0]);
+    assert_eq!(values("12,5 mg"), Vec::<f64>::new());
+
+    let text = "one million, two hundred thousand units";
+    assert_eq!(values(text), vec![1200000.0]);
+    assert_eq!(spans(text), vec!["one million, two hundred thousand"]);
+    assert_eq!(
+        values("one million, two hundred thousand, three hundred and five"),
+        vec![1200305.0]
+    );
+    // A comma before an equal or larger scale starts a new number.
+    assert_eq!(values("one thousand, two thousand"), vec![1000.0, 2000.0]);
+    assert_eq!(values("one hundred, two hundred"), vec![100.0, 200.0]);
+    assert_eq!(values("one thousand, and then two"), vec![1000.0, 2.0]);
+}
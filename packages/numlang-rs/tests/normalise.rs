@@ -0,0 +1,179 @@
+#![cfg(feature = "std")]
+
+use numlang::{normalise_text, Case, NormaliseOptions, NumberStyle, Options, UnitStyle};
+
+fn options(numbers: NumberStyle, units: UnitStyle) -> NormaliseOptions {
+    NormaliseOptions {
+        numbers,
+        units,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_filler_words_are_not_units() {
+    let opts = options(NumberStyle::Keep, UnitStyle::Name);
+    assert_eq!(
+        normalise_text("Take 1 um tablet", &opts),
+        "Take 1 um tablet"
+    );
+    assert_eq!(
+        normalise_text("Take 1 in the morning", &opts),
+        "Take 1 in the morning"
+    );
+    assert_eq!(normalise_text("a 5in bandage", &opts), "a 5 inches bandage");
+}
+
+#[test]
+fn test_keep_is_identity() {
+    let text = "  Give two tablets,  then 5 ml twice daily.\n";
+    assert_eq!(normalise_text(text, &NormaliseOptions::default()), text);
+}
+
+#[test]
+fn test_numbers_to_digits() {
+    let opts = options(NumberStyle::Digits, UnitStyle::Keep);
+    assert_eq!(
+        normalise_text("Give two hundred and five mg, then one tablet.", &opts),
+        "Give 205 mg, then 1 tablet."
+    );
+    assert_eq!(
+        normalise_text("one and two, then one and a half", &opts),
+        "1 and 2, then 1.5"
+    );
+    assert_eq!(
+        normalise_text("salt and pepper, to the point", &opts),
+        "salt and pepper, to the point"
+    );
+}
+
+#[test]
+fn test_numbers_to_words() {
+    let opts = options(NumberStyle::Words, UnitStyle::Keep);
+    assert_eq!(
+        normalise_text("Give 21 mg (2.5 ml).", &opts),
+        "Give twenty-one mg (two point five ml)."
+    );
+    assert_eq!(normalise_text("200g", &opts), "two hundred g");
+    assert_eq!(
+        normalise_text("Take 1-2 tablets", &opts),
+        "Take 1-2 tablets"
+    );
+}
+
+#[test]
+fn test_units_to_names() {
+    let opts = options(NumberStyle::Keep, UnitStyle::Name);
+    assert_eq!(
+        normalise_text("Give 2 ml then 1 ml, per ml", &opts),
+        "Give 2 milliliters then 1 milliliter, per ml"
+    );
+    assert_eq!(normalise_text("200g daily", &opts), "200 grams daily");
+    assert_eq!(
+        normalise_text("one mg or three milligram", &opts),
+        "one milligram or three milligrams"
+    );
+}
+
+#[test]
+fn test_case_applies_to_unit_names() {
+    let opts = NormaliseOptions {
+        numbers: NumberStyle::Words,
+        units: UnitStyle::Name,
+        words: Options::new().case(Case::Upper),
+    };
+    assert_eq!(normalise_text("Give 2 ml", &opts), "Give TWO MILLILITERS");
+    let opts = NormaliseOptions {
+        words: Options::new().case(Case::TitleCase),
+        ..opts
+    };
+    assert_eq!(normalise_text("2 ml", &opts), "Two Milliliters");
+    let opts = NormaliseOptions {
+        units: UnitStyle::Abbreviation,
+        words: Options::new().case(Case::Upper),
+        ..opts
+    };
+    assert_eq!(normalise_text("5 milliliters", &opts), "FIVE ml");
+}
+
+#[test]
+fn test_readme_examples() {
+    let opts = options(NumberStyle::Words, UnitStyle::Name);
+    assert_eq!(
+        normalise_text("Give 2 ml, then 1 ml.", &opts),
+        "Give two milliliters, then one milliliter."
+    );
+    let opts = options(NumberStyle::Digits, UnitStyle::Abbreviation);
+    assert_eq!(
+        normalise_text("two hundred and five milligrams", &opts),
+        "205 mg"
+    );
+}
+
+#[test]
+fn test_units_to_abbreviations() {
+    let opts = options(NumberStyle::Keep, UnitStyle::Abbreviation);
+    assert_eq!(
+        normalise_text("5 milligrams and 2 kilograms", &opts),
+        "5 mg and 2 kg"
+    );
+}
+
+#[test]
+fn test_numbers_and_units_for_speech() {
+    let opts = options(NumberStyle::Words, UnitStyle::Name);
+    assert_eq!(
+        normalise_text("Give 12kg dose of 1 ml.", &opts),
+        "Give twelve kilogram doses of one milliliter."
+    );
+}
+
+#[test]
+fn test_fractions_to_digits_stay_exact() {
+    let opts = options(NumberStyle::Digits, UnitStyle::Keep);
+    assert_eq!(
+        normalise_text("give one third of a tablet", &opts),
+        "give 1/3 of a tablet"
+    );
+    assert_eq!(
+        normalise_text("two and two thirds tablets", &opts),
+        "8/3 tablets"
+    );
+    assert_eq!(normalise_text("three quarters", &opts), "0.75");
+}
+
+#[test]
+fn test_abbreviations_agree_with_the_number() {
+    let opts = options(NumberStyle::Keep, UnitStyle::Abbreviation);
+    assert_eq!(normalise_text("0 tablets", &opts), "0 tablets");
+    assert_eq!(normalise_text("1.5 tablets", &opts), "1.5 tablets");
+    assert_eq!(normalise_text("2 tablet", &opts), "2 tablets");
+    assert_eq!(normalise_text("1 tablets", &opts), "1 tablet");
+    assert_eq!(normalise_text("2 doses, 3 vial", &opts), "2 doses, 3 vials");
+    assert_eq!(normalise_text("2 milligrams", &opts), "2 mg");
+    assert_eq!(normalise_text("0 milligrams", &opts), "0 mg");
+}
+
+#[test]
+fn test_comma_grouped_numbers() {
+    let opts = options(NumberStyle::Digits, UnitStyle::Abbreviation);
+    assert_eq!(
+        normalise_text("one million, two hundred thousand units", &opts),
+        "1200000 U"
+    );
+    assert_eq!(normalise_text("1,000 milligrams", &opts), "1,000 mg");
+}
+
+#[test]
+fn test_plural_unit_names_are_kept() {
+    let opts = options(NumberStyle::Keep, UnitStyle::Name);
+    assert_eq!(
+        normalise_text("2 milligrams per kilogram", &opts),
+        "2 milligrams per kilogram"
+    );
+    assert_eq!(normalise_text("5 IU", &opts), "5 international units");
+    assert_eq!(
+        normalise_text("5 international units", &opts),
+        "5 international units"
+    );
+}
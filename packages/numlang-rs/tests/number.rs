@@ -0,0 +1,141 @@
+use numlang::parse::parse_exact_with;
+use numlang::{parse_exact, Number, ParseMode};
+use std::collections::HashSet;
+
+#[test]
+fn test_integers() {
+    assert_eq!(parse_exact("forty-two"), Ok(Number::Integer(42)));
+    assert_eq!(parse_exact("42"), Ok(Number::Integer(42)));
+    assert_eq!(parse_exact("negative seven"), Ok(Number::Integer(-7)));
+    assert_eq!(
+        parse_exact("one decillion"),
+        Ok(Number::Integer(10i128.pow(33)))
+    );
+}
+
+#[test]
+fn test_rationals() {
+    let third = Number::Rational {
+        numerator: 1,
+        denominator: 3,
+    };
+    assert_eq!(parse_exact("one third"), Ok(third));
+    assert_eq!(
+        parse_exact("two thirds of a tablet"),
+        Ok(Number::Rational {
+            numerator: 2,
+            denominator: 3
+        })
+    );
+    assert_eq!(
+        parse_exact("one and a half"),
+        Ok(Number::Rational {
+            numerator: 3,
+            denominator: 2
+        })
+    );
+    assert_eq!(
+        parse_exact("two quarters"),
+        Ok(Number::rational(1, 2).unwrap())
+    );
+    assert_eq!(parse_exact("four quarters"), Ok(Number::Integer(1)));
+}
+
+#[test]
+fn test_decimals() {
+    assert_eq!(
+        parse_exact("twelve point five zero"),
+        Ok(Number::Decimal {
+            digits: 1250,
+            scale: 2
+        })
+    );
+    assert_eq!(
+        parse_exact("-0.25"),
+        Ok(Number::Decimal {
+            digits: -25,
+            scale: 2
+        })
+    );
+    assert!(parse_exact("1e5").is_err());
+}
+
+#[test]
+fn test_conversion_and_display() {
+    let two_thirds = parse_exact("two thirds").unwrap();
+    assert!((two_thirds.to_f64() - 2.0 / 3.0).abs() < 1e-12);
+    assert_eq!(f64::from(parse_exact("0.1").unwrap()), 0.1);
+    assert_eq!(two_thirds.to_string(), "2/3");
+    assert_eq!(parse_exact("12.50").unwrap().to_string(), "12.50");
+    assert_eq!(parse_exact("-0.05").unwrap().to_string(), "-0.05");
+    assert_eq!(Number::rational(4, -6).unwrap().to_string(), "-2/3");
+    assert_eq!(Number::rational(1, 0), None);
+}
+
+#[test]
+fn test_strict() {
+    assert!(parse_exact_with("one one", ParseMode::Strict).is_err());
+    assert_eq!(
+        parse_exact_with("three quarters", ParseMode::Strict),
+        Ok(Number::rational(3, 4).unwrap())
+    );
+}
+
+#[test]
+fn test_equality_by_value() {
+    let half = parse_exact("one half").unwrap();
+    assert_eq!(parse_exact("0.5").unwrap(), half);
+    assert_eq!(parse_exact("0.50").unwrap(), half);
+    assert_eq!(
+        Number::Rational {
+            numerator: -2,
+            denominator: -4
+        },
+        half
+    );
+    assert_eq!(parse_exact("3.0").unwrap(), Number::Integer(3));
+    assert_eq!(parse_exact("0.0").unwrap(), Number::Integer(0));
+    assert_ne!(parse_exact("0.3").unwrap(), Number::rational(1, 3).unwrap());
+    assert_ne!(half, Number::Integer(0));
+    // Finer than any i128 denominator, so only equal to itself.
+    let tiny = Number::Decimal {
+        digits: 1,
+        scale: 60,
+    };
+    assert_eq!(
+        tiny,
+        Number::Decimal {
+            digits: 100,
+            scale: 62
+        }
+    );
+    assert_ne!(tiny, Number::Integer(0));
+
+    let set: HashSet<Number> = ["0.5", "one half", "0.50", "two quarters", "0.25"]
+        .iter()
+        .map(|s| parse_exact(s).unwrap())
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_rational_overflow() {
+    assert_eq!(Number::rational(i128::MIN, -1), None);
+    assert_eq!(
+        Number::rational(i128::MIN, i128::MIN),
+        Some(Number::Integer(1))
+    );
+    assert_eq!(
+        Number::rational(i128::MIN, 1),
+        Some(Number::Integer(i128::MIN))
+    );
+    assert_eq!(
+        Number::rational(i128::MIN, 6),
+        Some(Number::Rational {
+            numerator: i128::MIN / 2,
+            denominator: 3
+        })
+    );
+    // A denominator of 2^127 doesn't fit once made positive.
+    assert_eq!(Number::rational(3, i128::MIN), None);
+}
@@ -0,0 +1,76 @@
+use numlang::{to_words, Case, Options};
+
+#[test]
+fn test_builder() {
+    assert_eq!(Options::new(), Options::default());
+    let opts = Options::new()
+        .use_commas(true)
+        .use_and(true)
+        .append_only(true)
+        .case(Case::SentenceCase);
+    assert!(opts.use_commas && opts.use_and && opts.append_only);
+    assert_eq!(opts.case, Case::SentenceCase);
+    assert_eq!(
+        to_words(1205, &opts),
+        "One thousand, two hundred and five only"
+    );
+    assert!(!opts.use_and(false).use_and);
+}
+
+#[test]
+fn test_case() {
+    assert_eq!(Case::default(), Case::Preserve);
+    assert_eq!(Case::Upper.apply("twenty-one"), "TWENTY-ONE");
+    assert_eq!(Case::Lower.apply("Twenty-One"), "twenty-one");
+    assert_eq!(Case::Preserve.apply("Twenty-one"), "Twenty-one");
+    assert_eq!(
+        Case::TitleCase.apply("a hundred and twenty-one"),
+        "A Hundred and Twenty-One"
+    );
+    assert_eq!(
+        Case::TitleCase.apply("andy and anderson of oft"),
+        "Andy and Anderson of Oft"
+    );
+    assert_eq!(
+        Case::SentenceCase.apply("take two. then one! 2 more? done"),
+        "Take two. Then one! 2 more? Done"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let opts = Options::new().use_and(true).case(Case::TitleCase);
+    let json = serde_json::to_string(&opts).unwrap();
+    assert_eq!(
+        json,
+        r#"{"use_commas":false,"use_and":true,"append_only":false,"case":"title_case"}"#
+    );
+    assert_eq!(serde_json::from_str::<Options>(&json).unwrap(), opts);
+    // Profiles only need the settings they change.
+    let partial: Options = serde_json::from_str(r#"{"use_commas":true}"#).unwrap();
+    assert_eq!(partial, Options::new().use_commas(true));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_reads_old_case_flags() {
+    let load = |json| serde_json::from_str::<Options>(json).unwrap();
+    assert_eq!(
+        load(r#"{"uppercase":true}"#),
+        Options::new().case(Case::Upper)
+    );
+    assert_eq!(
+        load(r#"{"capitalize":true,"use_and":true}"#),
+        Options::new().use_and(true).case(Case::SentenceCase)
+    );
+    assert_eq!(
+        load(r#"{"uppercase":true,"capitalize":true}"#),
+        Options::new().case(Case::Upper)
+    );
+    assert_eq!(
+        load(r#"{"uppercase":true,"case":"lower"}"#),
+        Options::new().case(Case::Lower)
+    );
+    assert_eq!(load(r#"{"uppercase":false}"#), Options::new());
+}
@@ -1,4 +1,4 @@
-use numlang::{to_ordinal, to_words_ordinal, Options};
+use numlang::{to_ordinal, to_words_ordinal, Case, Options};
 
 #[test]
 fn test_to_ordinal_basic() {
@@ -38,9 +38,32 @@ fn test_to_words_ordinal_formatting() {
     let mut opts = Options::default();
     opts.use_commas = true;
     opts.use_and = true;
-    opts.capitalize = true;
+    opts.case = Case::SentenceCase;
     assert_eq!(
         to_words_ordinal(1234, &opts),
         "One thousand, two hundred and thirty-fourth"
     );
 }
+
+#[test]
+fn test_to_words_ordinal_options_and_teens() {
+    assert_eq!(to_words_ordinal(12, &Options::default()), "twelfth");
+    let opts = Options::new().append_only(true).case(Case::Upper);
+    assert_eq!(to_words_ordinal(21, &opts), "TWENTY-FIRST ONLY");
+    let opts = Options::new().case(Case::TitleCase);
+    assert_eq!(to_words_ordinal(121, &opts), "One Hundred Twenty-First");
+}
+
+#[test]
+fn test_ordinal_display() {
+    use numlang::{write_words_ordinal, Ordinal};
+    assert_eq!(format!("the {} dose", Ordinal(3)), "the third dose");
+    let opts = Options::new().case(Case::Upper);
+    assert_eq!(
+        Ordinal(-21).with(&opts).to_string(),
+        "NEGATIVE TWENTY-FIRST"
+    );
+    let mut out = String::new();
+    write_words_ordinal(&mut out, i64::MIN, &Options::default()).unwrap();
+    assert!(out.ends_with("eight hundred eighth"), "{}", out);
+}
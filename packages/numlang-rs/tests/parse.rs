@@ -1,4 +1,5 @@
-use numlang::from_words;
+use numlang::parse::{from_words_with, ParseMode};
+use numlang::{from_words, ParseError, ParseErrorKind};
 
 #[test]
 fn test_basic_numbers() {
@@ -51,6 +52,18 @@ fn test_fractions() {
     assert!((from_words("two and three quarters").unwrap() - 2.75).abs() < 1e-9);
 }
 
+#[test]
+fn test_teen_and_tens_fractions() {
+    assert_eq!(from_words("one twentieth").unwrap(), 0.05);
+    assert_eq!(from_words("a twelfth").unwrap(), 1.0 / 12.0);
+    assert_eq!(from_words("three fiftieths").unwrap(), 0.06);
+    assert_eq!(from_words("two and one twentieth").unwrap(), 2.05);
+    // Without "one" or "a" they are ordinals.
+    assert_eq!(from_words("twentieth").unwrap(), 20.0);
+    assert_eq!(from_words("one hundred and twelfth").unwrap(), 112.0);
+    assert_eq!(from_words("one hundredth").unwrap(), 100.0);
+}
+
 #[test]
 fn test_frequency_adverbs() {
     assert_eq!(from_words("once").unwrap(), 1.0);
@@ -63,7 +76,17 @@ fn test_frequency_adverbs() {
 #[test]
 fn test_errors() {
     assert!(from_words("foo").is_err());
-    assert!(from_words("one foo").is_err());
+    assert_eq!(
+        from_words("one foo"),
+        Err(ParseError {
+            position: 4,
+            kind: ParseErrorKind::UnknownWord("foo".to_string()),
+        })
+    );
+    assert_eq!(
+        from_words("one point ten").unwrap_err().kind,
+        ParseErrorKind::InvalidDecimal("ten".to_string())
+    );
 }
 
 #[test]
@@ -73,3 +96,185 @@ fn test_number_strings() {
     assert_eq!(from_words("-7").unwrap(), -7.0);
     assert_eq!(from_words("0").unwrap(), 0.0);
 }
+
+#[test]
+fn test_and_between_parts() {
+    assert_eq!(from_words("two hundred and five").unwrap(), 205.0);
+    assert_eq!(from_words("one thousand and one").unwrap(), 1001.0);
+    assert_eq!(from_words("two and a half").unwrap(), 2.5);
+}
+
+#[test]
+fn test_strict_accepts_well_formed() {
+    for (words, value) in [
+        ("zero", 0.0),
+        ("forty-two", 42.0),
+        ("one hundred and five", 105.0),
+        ("a hundred", 100.0),
+        ("one thousand two hundred thirty-four", 1234.0),
+        ("two million three thousand", 2_003_000.0),
+        ("negative seven", -7.0),
+        ("twelve point three four", 12.34),
+        ("one and a half", 1.5),
+        ("three quarters", 0.75),
+        ("twice", 2.0),
+    ] {
+        assert_eq!(
+            from_words_with(words, ParseMode::Strict),
+            Ok(value),
+            "{}",
+            words
+        );
+    }
+}
+
+#[test]
+fn test_strict_rejects_malformed() {
+    for words in [
+        "one one one",
+        "hundred thousand hundred",
+        "five twenty",
+        "twenty twenty",
+        "one thousand two thousand",
+        "eleven five",
+        "zero one",
+        "one and two",
+        "two hundred and",
+        "seven negative",
+        "a",
+        "Twice daily",
+        "one half tablet",
+    ] {
+        assert!(
+            from_words_with(words, ParseMode::Strict).is_err(),
+            "{}",
+            words
+        );
+    }
+}
+
+#[test]
+fn test_strict_error_position() {
+    let strict = |words| from_words_with(words, ParseMode::Strict).unwrap_err();
+    let err = strict("one one");
+    assert_eq!(err.position, 4);
+    assert_eq!(
+        err.kind,
+        ParseErrorKind::Malformed("'one' can't come here".to_string())
+    );
+    assert_eq!(err.to_string(), "position 4: 'one' can't come here");
+    assert_eq!(
+        strict("two thousand three million").to_string(),
+        "position 19: 'million' is out of order"
+    );
+    assert_eq!(
+        strict("one half tablet").to_string(),
+        "position 9: unexpected 'tablet' after the number"
+    );
+    assert_eq!(strict("one point").position, 9);
+}
+
+#[test]
+fn test_lenient_is_default() {
+    assert_eq!(from_words("one one one"), Ok(3.0));
+    assert_eq!(from_words("five twenty"), Ok(25.0));
+    assert_eq!(from_words_with("five twenty", ParseMode::Lenient), Ok(25.0));
+}
+
+#[test]
+fn test_large_scales() {
+    assert_eq!(from_words("two quadrillion").unwrap(), 2e15);
+    assert_eq!(from_words("seven quintillion").unwrap(), 7e18);
+    assert_eq!(from_words("one decillion").unwrap(), 1e33);
+    assert_eq!(
+        from_words_with("nine hundred ninety-nine decillion", ParseMode::Strict).unwrap(),
+        999e33
+    );
+    assert_eq!(
+        from_words("nine hundred ninety-nine trillion trillion").unwrap(),
+        999e24
+    );
+    assert_eq!(from_words("one thousand thousand").unwrap(), 1e6);
+    assert_eq!(
+        from_words("two million three thousand").unwrap(),
+        2_003_000.0
+    );
+}
+
+#[test]
+fn test_overflow_is_an_error() {
+    let words = "one".to_string() + &" hundred".repeat(20);
+    assert_eq!(
+        from_words(&words).unwrap_err().kind,
+        ParseErrorKind::Overflow
+    );
+    for words in ["one million decillion", "one decillion decillion"] {
+        let err = from_words(words).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::Overflow, "{}", words);
+        assert!(matches!(
+            from_words_with(words, ParseMode::Strict).unwrap_err().kind,
+            ParseErrorKind::Malformed(_)
+        ));
+    }
+}
+
+#[test]
+fn test_strict_rejects_empty_input() {
+    for words in ["", "   ", "negative"] {
+        let err = from_words_with(words, ParseMode::Strict).unwrap_err();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Malformed("expected a number".to_string()),
+            "{:?}",
+            words
+        );
+        assert_eq!(err.position, words.len());
+    }
+    assert_eq!(
+        from_words_with("negative point five", ParseMode::Strict),
+        Ok(-0.5)
+    );
+    assert_eq!(from_words_with("half", ParseMode::Strict), Ok(0.5));
+}
+
+#[test]
+fn test_strict_hundreds_of_teens_and_tens() {
+    for (words, value) in [
+        ("twelve hundred", 1200.0),
+        ("nineteen hundred", 1900.0),
+        ("nineteen hundred and ninety-nine", 1999.0),
+        ("twenty-five hundred", 2500.0),
+        ("twelve hundred thousand", 1_200_000.0),
+    ] {
+        assert_eq!(
+            from_words_with(words, ParseMode::Strict),
+            Ok(value),
+            "{}",
+            words
+        );
+    }
+    for words in [
+        "twenty hundred",
+        "twelve hundred hundred",
+        "one thousand twelve hundred",
+    ] {
+        assert!(
+            from_words_with(words, ParseMode::Strict).is_err(),
+            "{}",
+            words
+        );
+    }
+}
+
+#[test]
+fn test_strict_reports_scale_order() {
+    let strict = |words| from_words_with(words, ParseMode::Strict).unwrap_err();
+    assert_eq!(
+        strict("one thousand decillion").to_string(),
+        "position 13: 'decillion' is out of order"
+    );
+    assert_eq!(
+        strict("one million thousand").to_string(),
+        "position 12: 'thousand' needs a number before it"
+    );
+}
@@ -1,3 +1,6 @@
+#![cfg(feature = "std")]
+
+use numlang::plural::{register_abbreviations, to_plural_with, to_singular_with, PluralOptions};
 use numlang::{to_plural, to_singular};
 
 #[test]
@@ -22,6 +25,59 @@ fn test_to_plural_irregular() {
     assert_eq!(to_plural("tooth"), "teeth");
 }
 
+#[test]
+fn test_irregular_dictionary() {
+    for (singular, plural) in [
+        ("child", "children"),
+        ("ox", "oxen"),
+        ("calf", "calves"),
+        ("analysis", "analyses"),
+        ("bacterium", "bacteria"),
+        ("fungus", "fungi"),
+        ("vertebra", "vertebrae"),
+        ("larva", "larvae"),
+    ] {
+        assert_eq!(to_plural(singular), plural);
+        assert_eq!(to_singular(plural), singular);
+    }
+    assert_eq!(to_plural("beef calf"), "beef calves");
+}
+
+#[test]
+fn test_unchanged_and_uncountable() {
+    for word in [
+        "sheep",
+        "fish",
+        "deer",
+        "species",
+        "milk",
+        "medication",
+        "ointment",
+    ] {
+        assert_eq!(to_plural(word), word);
+        assert_eq!(to_singular(word), word);
+    }
+}
+
+#[test]
+fn test_to_singular_es_endings() {
+    assert_eq!(to_singular("courses"), "course");
+    assert_eq!(to_singular("boluses"), "bolus");
+    assert_eq!(to_singular("glasses"), "glass");
+    assert_eq!(to_singular("sizes"), "size");
+    assert_eq!(to_singular("boxes"), "box");
+    assert_eq!(to_singular("gases"), "gas");
+    assert_eq!(to_singular("statuses"), "status");
+    assert_eq!(to_singular("viruses"), "virus");
+    assert_eq!(to_singular("causes"), "cause");
+    assert_eq!(to_singular("pauses"), "pause");
+    assert_eq!(to_singular("excuses"), "excuse");
+    assert_eq!(to_singular("houses"), "house");
+    assert_eq!(to_singular("quizzes"), "quiz");
+    assert_eq!(to_singular("buzzes"), "buzz");
+    assert_eq!(to_plural("quiz"), "quizzes");
+}
+
 #[test]
 fn test_to_plural_abbreviation() {
     assert_eq!(to_plural("mg"), "mg");
@@ -68,3 +124,128 @@ fn test_to_singular_pluralized_abbreviation() {
     assert_eq!(to_singular("ccs"), "cc");
     assert_eq!(to_singular("IUs"), "IU");
 }
+
+#[test]
+fn test_to_plural_multi_word() {
+    assert_eq!(to_plural("fluid ounce"), "fluid ounces");
+    assert_eq!(to_plural("ear tag"), "ear tags");
+    assert_eq!(
+        to_plural("milligram per kilogram"),
+        "milligrams per kilogram"
+    );
+    assert_eq!(
+        to_plural("milliliter per square centimeter tumour"),
+        "milliliters per square centimeter tumour"
+    );
+    assert_eq!(to_plural("course of treatment"), "courses of treatment");
+    assert_eq!(to_plural("drop/kg"), "drops/kg");
+    assert_eq!(to_plural("mg/kg"), "mg/kg");
+}
+
+#[test]
+fn test_to_singular_multi_word() {
+    assert_eq!(to_singular("fluid ounces"), "fluid ounce");
+    assert_eq!(to_singular("ear tags"), "ear tag");
+    assert_eq!(
+        to_singular("milligrams per kilogram"),
+        "milligram per kilogram"
+    );
+    assert_eq!(to_singular("courses of treatment"), "course of treatment");
+    assert_eq!(to_singular("drops/kg"), "drop/kg");
+}
+
+#[test]
+fn test_vowel_less_words_are_pluralised() {
+    assert_eq!(to_plural("gym"), "gyms");
+    assert_eq!(to_plural("myth"), "myths");
+    assert_eq!(to_plural("lynx"), "lynxes");
+    assert_eq!(to_singular("gyms"), "gym");
+}
+
+#[test]
+fn test_known_abbreviations_with_vowels() {
+    assert_eq!(to_plural("iu"), "iu");
+    assert_eq!(to_plural("meq"), "meq");
+    assert_eq!(to_plural("tbsp"), "tbsp");
+    assert_eq!(to_plural("fl oz"), "fl oz");
+    assert_eq!(to_singular("meqs"), "meq");
+    assert_eq!(to_singular("tbsps"), "tbsp");
+}
+
+#[test]
+fn test_abbreviations_that_are_words() {
+    for (singular, plural) in [
+        ("cap", "caps"),
+        ("app", "apps"),
+        ("amp", "amps"),
+        ("min", "mins"),
+        ("sec", "secs"),
+        ("gal", "gals"),
+        ("kg dose", "kg doses"),
+    ] {
+        assert_eq!(to_plural(singular), plural);
+        assert_eq!(to_singular(plural), singular);
+    }
+}
+
+#[test]
+fn test_abbreviation_heuristic_opt_in() {
+    let heuristic = PluralOptions {
+        abbreviation_heuristic: true,
+    };
+    assert_eq!(to_plural("bpm"), "bpms");
+    assert_eq!(to_plural_with("bpm", &heuristic), "bpm");
+    assert_eq!(to_singular_with("bpms", &heuristic), "bpm");
+}
+
+#[test]
+fn test_register_abbreviations() {
+    assert_eq!(to_plural("qds"), "qdses");
+    register_abbreviations(&["QDS", "prn"]);
+    assert_eq!(to_plural("qds"), "qds");
+    assert_eq!(to_plural("QDS"), "QDS");
+    assert_eq!(to_singular("prns"), "prn");
+}
+
+#[test]
+fn test_case_preserved() {
+    assert_eq!(to_plural("Foot"), "Feet");
+    assert_eq!(to_plural("MOUSE"), "MICE");
+    assert_eq!(to_plural("Tablet"), "Tablets");
+    assert_eq!(to_plural("TABLET"), "TABLETS");
+    assert_eq!(to_plural("Sheep"), "Sheep");
+    assert_eq!(
+        to_plural("Milligram per kilogram"),
+        "Milligrams per kilogram"
+    );
+    assert_eq!(to_singular("Children"), "Child");
+    assert_eq!(to_singular("TEETH"), "TOOTH");
+    assert_eq!(to_singular("MLS"), "ML");
+    assert_eq!(to_plural("mEq"), "mEq");
+}
+
+#[test]
+fn test_table_words_keep_their_number() {
+    for plural in ["children", "mice", "analyses", "data", "feet", "halves"] {
+        assert_eq!(to_plural(plural), plural);
+    }
+    for singular in ["gas", "quiz", "axis", "bolus", "status", "virus"] {
+        assert_eq!(to_singular(singular), singular);
+    }
+    assert_eq!(to_plural("Children"), "Children");
+    assert_eq!(to_singular("data"), "datum");
+}
+
+#[test]
+fn test_plural_heads_are_kept() {
+    for plural in [
+        "milligrams per kilogram",
+        "international units",
+        "Milligrams per kilogram",
+        "drops/kg",
+        "fluid ounces",
+    ] {
+        assert_eq!(to_plural(plural), plural);
+    }
+    assert_eq!(to_plural("international unit"), "international units");
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "std")]
+
+use numlang::{format_quantity, Case, Options, QuantityOptions};
+
+#[test]
+fn test_format_quantity_plurality() {
+    let opts = QuantityOptions::default();
+    assert_eq!(format_quantity(1, "tablet", &opts), "1 tablet");
+    assert_eq!(format_quantity(2, "tablet", &opts), "2 tablets");
+    assert_eq!(format_quantity(0.5, "tablet", &opts), "0.5 tablet");
+    assert_eq!(format_quantity(0, "tablet", &opts), "0 tablets");
+    assert_eq!(format_quantity(1.5, "patch", &opts), "1.5 patches");
+    assert_eq!(format_quantity(1, "tablets", &opts), "1 tablet");
+    assert_eq!(format_quantity(3, "widget", &opts), "3 widgets");
+}
+
+#[test]
+fn test_format_quantity_abbreviations() {
+    let opts = QuantityOptions::default();
+    assert_eq!(format_quantity(2, "ml", &opts), "2 ml");
+    assert_eq!(format_quantity(2, "mg", &opts), "2 mg");
+
+    let expand = QuantityOptions {
+        expand_unit: true,
+        ..QuantityOptions::default()
+    };
+    assert_eq!(format_quantity(2, "ml", &expand), "2 milliliters");
+    assert_eq!(format_quantity(1, "ml", &expand), "1 milliliter");
+    assert_eq!(format_quantity(2, "cap", &expand), "2 capsules");
+    assert_eq!(format_quantity(0.5, "mcg", &expand), "0.5 microgram");
+}
+
+#[test]
+fn test_format_quantity_decimals() {
+    let opts = QuantityOptions {
+        decimals: Some(1),
+        ..QuantityOptions::default()
+    };
+    assert_eq!(format_quantity(1, "ml", &opts), "1.0 ml");
+    assert_eq!(format_quantity(1.04, "tablet", &opts), "1.0 tablet");
+    assert_eq!(format_quantity(2.25, "tablet", &opts), "2.3 tablets");
+}
+
+#[test]
+fn test_format_quantity_fractions() {
+    let glyphs = QuantityOptions {
+        fractions: true,
+        ..QuantityOptions::default()
+    };
+    assert_eq!(format_quantity(1.5, "ml", &glyphs), "1½ ml");
+    assert_eq!(format_quantity(0.25, "tablet", &glyphs), "¼ tablet");
+    assert_eq!(format_quantity(2.75, "tablet", &glyphs), "2¾ tablets");
+    assert_eq!(format_quantity(0.3, "tablet", &glyphs), "0.3 tablet");
+
+    let words = QuantityOptions {
+        fractions: true,
+        spell_out: true,
+        ..QuantityOptions::default()
+    };
+    assert_eq!(format_quantity(0.5, "tablet", &words), "one half tablet");
+    assert_eq!(
+        format_quantity(1.5, "tablet", &words),
+        "one and a half tablets"
+    );
+    assert_eq!(
+        format_quantity(0.75, "tablet", &words),
+        "three quarters tablet"
+    );
+    assert_eq!(
+        format_quantity(2.0 / 3.0, "dose", &words),
+        "two thirds dose"
+    );
+}
+
+#[test]
+fn test_format_quantity_spell_out() {
+    let opts = QuantityOptions {
+        spell_out: true,
+        expand_unit: true,
+        words: Options::new().case(Case::SentenceCase),
+        ..QuantityOptions::default()
+    };
+    assert_eq!(format_quantity(2, "tablet", &opts), "Two tablets");
+    assert_eq!(format_quantity(1, "ml", &opts), "One milliliter");
+    assert_eq!(
+        format_quantity(2.5, "ml", &opts),
+        "Two point five milliliters"
+    );
+}
+
+#[test]
+fn test_format_quantity_case() {
+    let mut opts = QuantityOptions {
+        spell_out: true,
+        words: Options::new().case(Case::TitleCase),
+        ..QuantityOptions::default()
+    };
+    assert_eq!(format_quantity(21, "tablet", &opts), "Twenty-One Tablets");
+    // Abbreviations keep their case: "ML" would read as megaliters.
+    assert_eq!(format_quantity(2, "ml", &opts), "Two ml");
+    opts.words.case = Case::Upper;
+    assert_eq!(format_quantity(2, "ml", &opts), "TWO ml");
+    opts.expand_unit = true;
+    assert_eq!(format_quantity(2, "ml", &opts), "TWO MILLILITERS");
+    opts.words.case = Case::Preserve;
+    assert_eq!(format_quantity(2, "Blister", &opts), "two Blisters");
+    opts.words.case = Case::Lower;
+    assert_eq!(format_quantity(2, "Blister", &opts), "two blisters");
+}
+
+#[test]
+fn test_format_quantity_non_finite() {
+    let opts = QuantityOptions::default();
+    assert_eq!(
+        format_quantity(f64::NAN, "tablet", &opts),
+        "not a number tablets"
+    );
+    assert_eq!(format_quantity(f64::INFINITY, "ml", &opts), "infinity ml");
+    assert_eq!(
+        format_quantity(f64::NEG_INFINITY, "tablet", &opts),
+        "negative infinity tablets"
+    );
+}
@@ -0,0 +1,75 @@
+use numlang::{from_words, to_words, to_words_ordinal, Case, Options};
+use proptest::prelude::*;
+
+/// Integers `f64` holds exactly.
+const MAX_EXACT: i64 = 1 << 53;
+
+fn options() -> impl Strategy<Value = Options> {
+    let case = prop_oneof![
+        Just(Case::Lower),
+        Just(Case::Upper),
+        Just(Case::SentenceCase),
+        Just(Case::TitleCase),
+        Just(Case::Preserve),
+    ];
+    (any::<[bool; 3]>(), case).prop_map(|([use_commas, use_and, append_only], case)| {
+        Options::new()
+            .use_commas(use_commas)
+            .use_and(use_and)
+            .append_only(append_only)
+            .case(case)
+    })
+}
+
+proptest! {
+    #[test]
+    fn integers_round_trip(n in -MAX_EXACT..=MAX_EXACT, opts in options()) {
+        let words = to_words(n as f64, &opts);
+        prop_assert_eq!(from_words(&words), Ok(n as f64), "{}", words);
+    }
+
+    #[test]
+    fn large_integers_round_trip(mantissa in 1u64..1000, exponent in 15i32..36, opts in options()) {
+        let n = mantissa as f64 * 10f64.powi(exponent);
+        let words = to_words(n, &opts);
+        prop_assert_eq!(from_words(&words), Ok(n), "{}", words);
+    }
+
+    #[test]
+    fn decimals_round_trip(n in -1e9f64..1e9, opts in options()) {
+        let words = to_words(n, &opts);
+        prop_assert_eq!(from_words(&words), Ok(n), "{}", words);
+    }
+
+    #[test]
+    fn ordinals_round_trip(n in -MAX_EXACT..=MAX_EXACT, opts in options()) {
+        let words = to_words_ordinal(n, &opts);
+        prop_assert_eq!(from_words(&words), Ok(n as f64), "{}", words);
+    }
+}
+
+#[test]
+fn test_round_trip_examples() {
+    let commas = Options::new().use_commas(true);
+    assert_eq!(to_words(1_000_200, &commas), "one million, two hundred");
+    assert_eq!(from_words("one million, two hundred"), Ok(1_000_200.0));
+    assert_eq!(
+        from_words("ONE THOUSAND TWO HUNDRED THIRTY-FOUR ONLY"),
+        Ok(1234.0)
+    );
+    assert_eq!(to_words(7e30, &Options::default()), "seven nonillion");
+    assert_eq!(from_words("seven nonillion"), Ok(7e30));
+}
+
+#[test]
+fn test_ordinal_words() {
+    assert_eq!(from_words("first"), Ok(1.0));
+    assert_eq!(from_words("twelfth"), Ok(12.0));
+    assert_eq!(from_words("twenty-third"), Ok(23.0));
+    assert_eq!(from_words("one hundred and third"), Ok(103.0));
+    assert_eq!(from_words("one thousandth"), Ok(1000.0));
+    assert_eq!(from_words("ninetieth"), Ok(90.0));
+    // After "one" or "a", "third" is still a fraction.
+    assert!((from_words("one third").unwrap() - 1.0 / 3.0).abs() < 1e-12);
+    assert!((from_words("two and a third").unwrap() - 7.0 / 3.0).abs() < 1e-12);
+}
@@ -1,4 +1,7 @@
-use numlang::tokenise::{tokenise, Token};
+#![cfg(feature = "std")]
+
+use numlang::tokenise::{tokenise, tokenise_with, Token};
+use numlang::UnitCase;
 
 #[test]
 fn test_tokenise_examples() {
@@ -108,7 +111,7 @@ fn test_tokenise_punctuation() {
 #[test]
 fn test_tokenise_positions() {
     let input = "100g of sugar";
-    let expected = vec![
+    let expected = [
         (Token::NumberString("100".to_string()), 0, 3),
         (Token::Unit("g".to_string()), 3, 4),
         (Token::Unknown("of".to_string()), 5, 7),
@@ -351,3 +354,87 @@ fn test_attached_number_compound_unit_in_sentence() {
         .expect("NumberString(12) not found");
     assert_eq!(tokens[num_idx + 1], Token::Unit("kg dose".into()));
 }
+
+#[test]
+fn test_tokenise_si_prefixed_units() {
+    let tokens: Vec<Token> = tokenise("5µl then 20 nmol")
+        .into_iter()
+        .map(|span| span.token)
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::NumberString("5".to_string()),
+            Token::Unit("µl".to_string()),
+            Token::Unknown("then".to_string()),
+            Token::NumberString("20".to_string()),
+            Token::Unit("nmol".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_tokenise_lowercased_giga_is_not_a_unit() {
+    let tokens: Vec<Token> = tokenise("5 gm")
+        .into_iter()
+        .map(|span| span.token)
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::NumberString("5".to_string()),
+            Token::Unknown("gm".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_spans_index_untrimmed_input() {
+    let input = "  give 5 ml";
+    let tokens = tokenise(input);
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(&input[tokens[1].start..tokens[1].end], "5");
+    assert_eq!(&input[tokens[2].start..tokens[2].end], "ml");
+}
+
+#[test]
+fn test_tokenise_case_sensitive_units() {
+    let units = |input, case| -> Vec<String> {
+        tokenise_with(input, case)
+            .into_iter()
+            .filter_map(|span| match span.token {
+                Token::Unit(unit) => Some(unit),
+                _ => None,
+            })
+            .collect()
+    };
+    assert_eq!(units("5 mL then 5 ML", UnitCase::Sensitive), ["mL", "ML"]);
+    assert_eq!(units("20MG or 20mg", UnitCase::Sensitive), ["mg"]);
+    assert_eq!(units("20MG or 20mg", UnitCase::Insensitive), ["MG", "mg"]);
+    assert_eq!(units("20MG or 20mg", UnitCase::Lenient), ["MG", "mg"]);
+    assert_eq!(units("5 IU, 5 iu", UnitCase::Sensitive), ["IU"]);
+}
+
+#[test]
+fn test_tokenise_capitalised_prefixed_symbols() {
+    let input = "5 MHz, 5 GHz, 5 GL, 5Gg";
+    for case in [UnitCase::Sensitive, UnitCase::Lenient] {
+        let units: Vec<Token> = tokenise_with(input, case)
+            .into_iter()
+            .map(|span| span.token)
+            .filter(|token| matches!(token, Token::Unit(_)))
+            .collect();
+        assert_eq!(
+            units,
+            [
+                Token::Unit("MHz".to_string()),
+                Token::Unit("GHz".to_string()),
+                Token::Unit("GL".to_string()),
+                Token::Unit("Gg".to_string()),
+            ]
+        );
+    }
+    assert!(tokenise_with(input, UnitCase::Insensitive)
+        .iter()
+        .all(|span| !matches!(span.token, Token::Unit(_))));
+}
@@ -1,55 +1,73 @@
-use numlang::{abbreviate_unit, expand_unit, unit_type, UnitType};
+#![cfg(feature = "std")]
+
+use numlang::{abbreviate_unit, aliases, expand_unit, resolve_unit, unit_type, UnitCase, UnitType};
 
 #[test]
 fn test_expand_unit_basic() {
-    assert_eq!(expand_unit("ml"), Some("milliliter"));
-    assert_eq!(expand_unit("MG"), Some("milligram"));
-    assert_eq!(expand_unit("kg"), Some("kilogram"));
-    assert_eq!(expand_unit("oz"), Some("ounce"));
-    assert_eq!(expand_unit("IU"), Some("international unit"));
-    assert_eq!(expand_unit("mcg"), Some("microgram"));
-    assert_eq!(expand_unit("μg"), Some("microgram"));
-    assert_eq!(expand_unit("cc"), Some("cubic centimeter"));
-    assert_eq!(expand_unit("gtt"), Some("drop"));
-    assert_eq!(expand_unit("cap"), Some("capsule"));
-    assert_eq!(expand_unit("patch"), Some("patch"));
-    assert_eq!(expand_unit("dose"), Some("dose"));
-    assert_eq!(expand_unit("unknown"), None);
+    assert_eq!(expand_unit("ml").as_deref(), Some("milliliter"));
+    assert_eq!(expand_unit("MG").as_deref(), Some("milligram"));
+    assert_eq!(expand_unit("kg").as_deref(), Some("kilogram"));
+    assert_eq!(expand_unit("oz").as_deref(), Some("ounce"));
+    assert_eq!(expand_unit("IU").as_deref(), Some("international unit"));
+    assert_eq!(expand_unit("mcg").as_deref(), Some("microgram"));
+    assert_eq!(expand_unit("μg").as_deref(), Some("microgram"));
+    assert_eq!(expand_unit("cc").as_deref(), Some("cubic centimeter"));
+    assert_eq!(expand_unit("gtt").as_deref(), Some("drop"));
+    assert_eq!(expand_unit("cap").as_deref(), Some("capsule"));
+    assert_eq!(expand_unit("patch").as_deref(), Some("patch"));
+    assert_eq!(expand_unit("dose").as_deref(), Some("dose"));
+    assert_eq!(expand_unit("unknown").as_deref(), None);
 }
 
 #[test]
 fn test_expand_unit_plural_and_case() {
-    assert_eq!(expand_unit("cap"), Some("capsule"));
-    assert_eq!(expand_unit("chew"), Some("chew"));
+    assert_eq!(expand_unit("cap").as_deref(), Some("capsule"));
+    assert_eq!(expand_unit("chew").as_deref(), Some("chew"));
 }
 
 #[test]
 fn test_abbreviate_unit_singular() {
-    assert_eq!(abbreviate_unit("milliliter"), Some("ml"));
-    let drop_abbr = abbreviate_unit("drop");
-    assert!(drop_abbr == Some("gtt") || drop_abbr == Some("drop"));
-    let abbr = abbreviate_unit("microgram");
-    assert!(abbr == Some("mcg") || abbr == Some("μg"));
+    assert_eq!(abbreviate_unit("milliliter").as_deref(), Some("ml"));
+    assert_eq!(abbreviate_unit("drop").as_deref(), Some("gtt"));
+    assert_eq!(abbreviate_unit("microgram").as_deref(), Some("mcg"));
+    assert_eq!(abbreviate_unit("capsule").as_deref(), Some("cap"));
+    assert_eq!(abbreviate_unit("hour").as_deref(), Some("h"));
 }
 
 #[test]
 fn test_abbreviate_unit_plural() {
-    assert_eq!(abbreviate_unit("milliliters"), Some("ml"));
-    let drops_abbr = abbreviate_unit("drops");
-    assert!(drops_abbr == Some("gtt") || drops_abbr == Some("drop"));
-    let abbr = abbreviate_unit("micrograms");
-    assert!(abbr == Some("mcg") || abbr == Some("μg"));
+    assert_eq!(abbreviate_unit("milliliters").as_deref(), Some("ml"));
+    assert_eq!(abbreviate_unit("drops").as_deref(), Some("gtt"));
+    assert_eq!(abbreviate_unit("micrograms").as_deref(), Some("mcg"));
+    assert_eq!(
+        abbreviate_unit("micrograms per kilogram").as_deref(),
+        Some("mcg/kg")
+    );
+    assert_eq!(
+        abbreviate_unit("microgram per kilogram").as_deref(),
+        Some("mcg/kg")
+    );
 }
 
 #[test]
 fn test_abbreviate_unit_case_insensitive() {
-    assert_eq!(abbreviate_unit("Milliliters"), Some("ml"));
+    assert_eq!(abbreviate_unit("Milliliters").as_deref(), Some("ml"));
 }
 
 #[test]
 fn test_abbreviate_unit_unknown() {
-    assert_eq!(abbreviate_unit("unknownunit"), None);
-    assert_eq!(abbreviate_unit(""), None);
+    assert_eq!(abbreviate_unit("unknownunit").as_deref(), None);
+    assert_eq!(abbreviate_unit("").as_deref(), None);
+}
+
+#[test]
+fn test_aliases() {
+    assert_eq!(aliases("microgram").unwrap(), vec!["mcg", "μg", "µg", "ug"]);
+    assert_eq!(aliases("μg").unwrap(), vec!["mcg", "μg", "µg", "ug"]);
+    assert_eq!(aliases("drops").unwrap(), vec!["gtt", "drop"]);
+    assert_eq!(aliases("HR").unwrap(), vec!["h", "hr"]);
+    assert_eq!(aliases("ml").unwrap(), vec!["ml"]);
+    assert_eq!(aliases("unknownunit"), None);
 }
 
 #[test]
@@ -75,3 +93,149 @@ fn test_unit_type_expanded_and_plural() {
     assert_eq!(unit_type("hours"), Some(UnitType::Time));
     assert_eq!(unit_type("unknownunit"), None);
 }
+
+#[test]
+fn test_resolve_unit_case_sensitive() {
+    let name = |unit, case| resolve_unit(unit, case).map(|m| m.def.name.clone());
+    assert_eq!(
+        name("mL", UnitCase::Sensitive).as_deref(),
+        Some("milliliter")
+    );
+    assert_eq!(
+        name("ml", UnitCase::Sensitive).as_deref(),
+        Some("milliliter")
+    );
+    assert_eq!(name("MG", UnitCase::Sensitive), None);
+    assert_eq!(
+        name("MU", UnitCase::Sensitive).as_deref(),
+        Some("million units")
+    );
+    assert_eq!(name("mu", UnitCase::Sensitive), None);
+    assert_eq!(name("Mg", UnitCase::Sensitive).as_deref(), Some("megagram"));
+    assert_eq!(
+        name("ML", UnitCase::Sensitive).as_deref(),
+        Some("megaliter")
+    );
+    assert_eq!(name("M", UnitCase::Sensitive), None);
+    assert_eq!(
+        name("IU", UnitCase::Sensitive).as_deref(),
+        Some("international unit")
+    );
+    assert_eq!(
+        name("μg", UnitCase::Sensitive).as_deref(),
+        Some("microgram")
+    );
+    // Non-symbol spellings and full names ignore case.
+    assert_eq!(name("HR", UnitCase::Sensitive).as_deref(), Some("hour"));
+    assert_eq!(
+        name("Tablet", UnitCase::Sensitive).as_deref(),
+        Some("tablet")
+    );
+    assert_eq!(
+        name("Milliliters", UnitCase::Sensitive).as_deref(),
+        Some("milliliter")
+    );
+    assert_eq!(
+        name("ML", UnitCase::Insensitive).as_deref(),
+        Some("milliliter")
+    );
+}
+
+#[test]
+fn test_resolve_unit_lenient_reports_guess() {
+    let exact = resolve_unit("mL", UnitCase::Lenient).unwrap();
+    assert_eq!(exact.def.name, "milliliter");
+    assert!(!exact.guessed);
+
+    let guess = resolve_unit("MG", UnitCase::Lenient).unwrap();
+    assert_eq!(guess.def.name, "milligram");
+    assert!(guess.guessed);
+
+    assert!(!resolve_unit("ML", UnitCase::Insensitive).unwrap().guessed);
+    assert_eq!(resolve_unit("unknownunit", UnitCase::Lenient), None);
+}
+
+#[test]
+fn test_abbreviations_resolve_case_sensitively() {
+    assert_eq!(abbreviate_unit("international unit").as_deref(), Some("IU"));
+    assert_eq!(abbreviate_unit("milliequivalents").as_deref(), Some("mEq"));
+    assert_eq!(abbreviate_unit("unit").as_deref(), Some("U"));
+    assert_eq!(abbreviate_unit("kilohertz").as_deref(), Some("kHz"));
+    assert_eq!(abbreviate_unit("milliliter").as_deref(), Some("ml"));
+    for def in numlang::unit::units().iter() {
+        let abbreviation = abbreviate_unit(&def.name).unwrap();
+        assert!(
+            resolve_unit(&abbreviation, UnitCase::Sensitive).is_some(),
+            "{} ({}) doesn't resolve case-sensitively",
+            abbreviation,
+            def.name
+        );
+    }
+}
+
+#[test]
+fn test_si_prefixed_units() {
+    assert_eq!(expand_unit("ng").as_deref(), Some("nanogram"));
+    assert_eq!(expand_unit("nmol").as_deref(), Some("nanomole"));
+    assert_eq!(expand_unit("kl").as_deref(), Some("kiloliter"));
+    assert_eq!(expand_unit("µl").as_deref(), Some("microliter"));
+    assert_eq!(expand_unit("μl").as_deref(), Some("microliter"));
+    assert_eq!(expand_unit("mcL").as_deref(), Some("microliter"));
+    assert_eq!(expand_unit("kHz").as_deref(), Some("kilohertz"));
+    // "miu" is both milli- and mega- international units once case is
+    // ignored, so only the case-exact symbols resolve.
+    assert_eq!(expand_unit("mIU"), None);
+    assert_eq!(expand_unit("MIU"), None);
+    assert_eq!(resolve_unit("miu", UnitCase::Sensitive), None);
+    assert_eq!(
+        resolve_unit("mIU", UnitCase::Lenient).unwrap().def.name,
+        "milli international unit"
+    );
+    assert_eq!(
+        resolve_unit("MIU", UnitCase::Sensitive).unwrap().def.name,
+        "mega international units"
+    );
+    assert_eq!(unit_type("nmol"), Some(UnitType::Other));
+    assert_eq!(unit_type("nanograms"), Some(UnitType::Mass));
+    assert_eq!(unit_type("dm"), Some(UnitType::Length));
+    assert_eq!(abbreviate_unit("nanogram").as_deref(), Some("ng"));
+    assert_eq!(abbreviate_unit("megagram").as_deref(), Some("Mg"));
+    assert_eq!(abbreviate_unit("millimoles").as_deref(), Some("mmol"));
+}
+
+#[test]
+fn test_si_prefix_case() {
+    let name = |unit| resolve_unit(unit, UnitCase::Sensitive).map(|m| m.def.name.clone());
+    assert_eq!(name("MHz").as_deref(), Some("megahertz"));
+    assert_eq!(name("GHz").as_deref(), Some("gigahertz"));
+    assert_eq!(name("Mm").as_deref(), Some("megameter"));
+    assert_eq!(name("mm").as_deref(), Some("millimeter"));
+    assert_eq!(name("GL").as_deref(), Some("gigaliter"));
+    assert_eq!(name("mIU").as_deref(), Some("milli international unit"));
+    // Insensitive lookups resolve case collisions to the smaller prefix.
+    assert_eq!(expand_unit("Mm").as_deref(), Some("millimeter"));
+}
+
+#[test]
+fn test_si_case_collisions_invent_nothing() {
+    // Lowercased mega and giga spellings aren't units: "gm" is a common
+    // spelling of gram, and "mhz" of MHz.
+    assert_eq!(expand_unit("gm"), None);
+    assert_eq!(expand_unit("Gm"), None);
+    assert_eq!(expand_unit("mhz"), None);
+    assert_eq!(expand_unit("ghz"), None);
+    assert_eq!(resolve_unit("mhz", UnitCase::Sensitive), None);
+    assert_eq!(resolve_unit("mHz", UnitCase::Sensitive), None);
+    // "um" is a filler word; micrometers are "mcm" or "µm".
+    assert_eq!(expand_unit("um"), None);
+    assert_eq!(expand_unit("µm").as_deref(), Some("micrometer"));
+}
+
+#[test]
+fn test_microliter_keeps_ul() {
+    assert_eq!(abbreviate_unit("microliters").as_deref(), Some("ul"));
+    assert_eq!(
+        aliases("microliter").unwrap(),
+        vec!["ul", "mcl", "μl", "µl"]
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "std")]
+
+use numlang::tokenise::{tokenise, Token};
+use numlang::unit_file::{load_units, parse_units};
+use numlang::unit::units;
+use numlang::{abbreviate_unit, aliases, expand_unit, unit_type, UnitType};
+
+const UNITS: &str = "
+# Product-specific dosage forms
+abbreviation, name, type, aliases, plural, factor
+lsc, large scoop, dosage form, large scoop; lg scoop, large scoops, 5
+gn, grain, Mass, gr, , 0.0648
+";
+
+#[test]
+fn test_parse_units() {
+    let defs = parse_units(UNITS).unwrap();
+    assert_eq!(defs.len(), 2);
+    assert_eq!(defs[0].abbreviation, "lsc");
+    assert_eq!(defs[0].aliases, &["large scoop", "lg scoop"]);
+    assert_eq!(defs[0].name, "large scoop");
+    assert_eq!(defs[0].unit_type, UnitType::DosageForm);
+    assert_eq!(defs[0].plural.as_deref(), Some("large scoops"));
+    assert_eq!(defs[0].factor, Some(5.0));
+    assert_eq!(defs[1].plural, None);
+    assert_eq!(defs[1].factor, Some(0.0648));
+}
+
+#[test]
+fn test_parse_units_errors() {
+    assert!(parse_units("abbreviation, name\nx, y").is_err());
+    assert!(parse_units("abbreviation, name, type, colour\n").is_err());
+    let err = parse_units("abbreviation, name, type\nx, y, furlongs").unwrap_err();
+    assert!(err.starts_with("line 2:"), "{}", err);
+    assert!(parse_units("abbreviation, name, type, factor\nx, y, mass, lots").is_err());
+    assert!(parse_units("abbreviation, name, type\nx, , mass").is_err());
+    assert!(parse_units("abbreviation, name, type\nx, y, mass, extra").is_err());
+    let err = parse_units("abbreviation, name, type\nx, \"y, mass").unwrap_err();
+    assert_eq!(err, "line 2: unterminated quote");
+    assert!(parse_units("abbreviation, name, type\nx, \"y\" z, mass").is_err());
+}
+
+#[test]
+fn test_parse_units_quoted_cells() {
+    let defs = parse_units(
+        "abbreviation, name, type, plural\n\
+         ctab, \"tablet, chewable\", dosage form, \"tablets, chewable\"\n\
+         \"q\"\"\", \"quote \"\"unit\"\"\", other,",
+    )
+    .unwrap();
+    assert_eq!(defs[0].name, "tablet, chewable");
+    assert_eq!(defs[0].plural.as_deref(), Some("tablets, chewable"));
+    assert_eq!(defs[1].abbreviation, "q\"");
+    assert_eq!(defs[1].name, "quote \"unit\"");
+    assert_eq!(defs[1].plural, None);
+}
+
+#[test]
+fn test_load_units_registers() {
+    let path = std::env::temp_dir().join(format!("numlang-units-{}.csv", std::process::id()));
+    std::fs::write(&path, UNITS).unwrap();
+    assert_eq!(load_units(&path), Ok(2));
+    // Reloading replaces the units rather than adding them again.
+    let count = units().len();
+    assert_eq!(load_units(&path), Ok(2));
+    assert_eq!(units().len(), count);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(expand_unit("lsc").as_deref(), Some("large scoop"));
+    assert_eq!(expand_unit("GR").as_deref(), Some("grain"));
+    assert_eq!(unit_type("large scoops"), Some(UnitType::DosageForm));
+    assert_eq!(unit_type("grains"), Some(UnitType::Mass));
+    assert_eq!(abbreviate_unit("large scoops").as_deref(), Some("lsc"));
+    assert_eq!(aliases("grain").unwrap(), vec!["gn", "gr"]);
+    // Built-in units are still there.
+    assert_eq!(expand_unit("mg").as_deref(), Some("milligram"));
+
+    let tokens: Vec<Token> = tokenise("give 2 lg scoop daily")
+        .into_iter()
+        .map(|span| span.token)
+        .collect();
+    assert_eq!(tokens[2], Token::Unit("lg scoop".to_string()));
+}
+
+#[test]
+fn test_load_units_missing_file() {
+    assert!(load_units("/nonexistent/numlang-units.csv").is_err());
+}